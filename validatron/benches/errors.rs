@@ -0,0 +1,78 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use validatron::Validate;
+
+// counts allocations, so that the cost of building errors can be reported alongside
+// their timings
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[derive(Clone, Validate)]
+struct Reading {
+    #[validatron(min = 1, max = 60)]
+    minutes: u64,
+    #[validatron(min = -90.0, max = 90.0)]
+    latitude: f64,
+    #[validatron(max_len = 4)]
+    samples: Vec<u32>,
+    #[validatron(required)]
+    station: Option<u32>,
+}
+
+// every reading fails each of its checks
+fn invalid_payload(n: usize) -> Vec<Reading> {
+    let reading = Reading {
+        minutes: 0,
+        latitude: 91.5,
+        samples: vec![0; 5],
+        station: None,
+    };
+
+    vec![reading; n]
+}
+
+fn large_invalid_payload(c: &mut Criterion) {
+    let payload = invalid_payload(10_000);
+
+    println!(
+        "large_invalid_payload: {} allocations to validate, {} more to display",
+        allocations(|| payload.validate()),
+        allocations(|| payload.validate().unwrap_err().to_string())
+            - allocations(|| payload.validate()),
+    );
+
+    let mut group = c.benchmark_group("large_invalid_payload");
+
+    group.bench_function("validate", |b| b.iter(|| black_box(&payload).validate()));
+    group.bench_function("validate_and_display", |b| {
+        b.iter(|| black_box(&payload).validate().unwrap_err().to_string())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, large_invalid_payload);
+criterion_main!(benches);
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use validatron::Validate;
+
+#[derive(Clone, Validate)]
+struct Window {
+    #[validatron(min = 1, max = 60)]
+    minutes: u64,
+    #[validatron(option_min = 0.0, option_max = 90.0)]
+    elevation: Option<f64>,
+}
+
+#[derive(Clone, Validate)]
+struct Pass {
+    #[validatron(lte_field = "end")]
+    start: u64,
+    end: u64,
+    #[validatron(min_len = 1, each(min = 1))]
+    stations: Vec<u32>,
+    #[validatron]
+    window: Window,
+}
+
+#[derive(Clone, Validate)]
+struct Schedule {
+    #[validatron(equal = 1)]
+    version: u64,
+    #[validatron]
+    passes: Vec<Pass>,
+    #[validatron]
+    backups: BTreeMap<String, Pass>,
+}
+
+fn pass(minutes: u64) -> Pass {
+    Pass {
+        start: 0,
+        end: 10,
+        stations: vec![1, 2, 3],
+        window: Window {
+            minutes,
+            elevation: Some(45.0),
+        },
+    }
+}
+
+fn schedule(passes: usize) -> Schedule {
+    Schedule {
+        version: 1,
+        passes: (0..passes).map(|_| pass(10)).collect(),
+        backups: (0..10).map(|i| (i.to_string(), pass(10))).collect(),
+    }
+}
+
+fn compare<T: Validate>(c: &mut Criterion, group: &str, cases: &[(&str, T)]) {
+    let mut group = c.benchmark_group(group);
+
+    for (name, value) in cases {
+        group.bench_with_input(BenchmarkId::new("is_valid", name), value, |b, x| {
+            b.iter(|| black_box(x).is_valid())
+        });
+        group.bench_with_input(BenchmarkId::new("validate", name), value, |b, x| {
+            b.iter(|| black_box(x).validate().is_ok())
+        });
+    }
+
+    group.finish();
+}
+
+fn nested_structs(c: &mut Criterion) {
+    let mut invalid = schedule(100);
+    invalid.passes[0].window.minutes = 0;
+
+    compare(
+        c,
+        "nested_structs",
+        &[("valid", schedule(100)), ("invalid", invalid)],
+    );
+}
+
+fn large_vectors(c: &mut Criterion) {
+    let valid = vec![pass(10).window; 100_000];
+
+    let mut invalid_early = valid.clone();
+    invalid_early[10].minutes = 0;
+
+    let mut all_invalid = valid.clone();
+    all_invalid.iter_mut().for_each(|x| x.minutes = 61);
+
+    compare(
+        c,
+        "large_vectors",
+        &[
+            ("valid", valid),
+            ("invalid_early", invalid_early),
+            ("all_invalid", all_invalid),
+        ],
+    );
+}
+
+criterion_group!(benches, nested_structs, large_vectors);
+criterion_main!(benches);
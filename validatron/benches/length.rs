@@ -0,0 +1,57 @@
+use std::collections::{BTreeSet, HashMap, LinkedList};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use validatron::validators::is_min_length;
+use validatron::Validate;
+
+const LEN: usize = 1_000_000;
+
+#[derive(Validate)]
+struct Lists {
+    #[validatron(min_len = 1)]
+    vec: Vec<u64>,
+    #[validatron(min_len = 1)]
+    map: HashMap<u64, u64>,
+    #[validatron(min_len = 1)]
+    set: BTreeSet<u64>,
+    #[validatron(min_len = 1)]
+    list: LinkedList<u64>,
+}
+
+fn compare<C>(c: &mut Criterion, name: &str, value: &C)
+where
+    for<'a> &'a C: IntoIterator,
+{
+    let mut group = c.benchmark_group("min_len");
+
+    group.bench_with_input(BenchmarkId::new("size_hint", name), value, |b, x| {
+        b.iter(|| is_min_length(black_box(x), 1))
+    });
+    // hides the size hint, so that every item is counted
+    group.bench_with_input(BenchmarkId::new("counted", name), value, |b, x| {
+        b.iter(|| is_min_length(black_box(x).into_iter().filter(|_| true), 1))
+    });
+
+    group.finish();
+}
+
+fn collections(c: &mut Criterion) {
+    let lists = Lists {
+        vec: (0..LEN as u64).collect(),
+        map: (0..LEN as u64).map(|x| (x, x)).collect(),
+        set: (0..LEN as u64).collect(),
+        list: (0..LEN as u64).collect(),
+    };
+
+    compare(c, "vec", &lists.vec);
+    compare(c, "hash_map", &lists.map);
+    compare(c, "btree_set", &lists.set);
+    compare(c, "linked_list", &lists.list);
+
+    c.bench_function("min_len/derived", |b| {
+        b.iter(|| black_box(&lists).validate())
+    });
+}
+
+criterion_group!(benches, collections);
+criterion_main!(benches);
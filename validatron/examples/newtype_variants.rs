@@ -5,6 +5,7 @@ fn main() {
     struct Unit();
     assert!(Unit().validate().is_ok());
 
+    #[allow(dead_code)]
     #[derive(Validate)]
     struct NewTypeA(Option<i32>);
     assert!(NewTypeA(None).validate().is_ok());
@@ -0,0 +1,560 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::{Error, Result, Validate};
+
+/// Validation that needs to await I/O, such as checking that a referenced identifier
+/// exists in a data store.
+///
+/// The trait is runtime agnostic, it only returns a future for the caller to await.
+/// Deriving [`Validate`](crate::Validate) on a type that uses
+/// `#[validatron(async_function = "path")]`, or that is annotated with
+/// `#[validatron(asynchronous)]`, implements `AsyncValidate` instead. The checks of every
+/// field are evaluated concurrently and their failures are collected into a single
+/// [`enum@Error`].
+///
+/// The returned future is `Send`, so that validation can be spawned onto a multithreaded
+/// runtime. Implementors must therefore be `Sync`, and the futures returned by
+/// `async_function`s must be `Send`. Derived implementations apply to the instances of generic
+/// types which are `Sync`.
+///
+/// Types that derive plain [`Validate`](crate::Validate) don't implement `AsyncValidate`.
+/// Nested fields which don't implement it, including types implementing `Validate` by hand
+/// and collections of them, are validated synchronously with `Validate` instead.
+///
+/// ```
+/// use validatron::{AsyncValidate, Error, Result, Validate};
+///
+/// async fn exists(id: &u32) -> Result<()> {
+///     // a lookup against a real data store would be awaited here
+///     if *id < 100 {
+///         Ok(())
+///     } else {
+///         Err(Error::new(format!("satellite {} does not exist", id)))
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct Contact {
+///     #[validatron(async_function = "exists")]
+///     satellite: u32,
+///     #[validatron(min = 1)]
+///     passes: u64,
+/// }
+///
+/// # fn block_on<F: std::future::Future>(f: F) -> F::Output {
+/// #     let mut f = std::pin::pin!(f);
+/// #     struct Noop;
+/// #     impl std::task::Wake for Noop {
+/// #         fn wake(self: std::sync::Arc<Self>) {}
+/// #     }
+/// #     let waker = std::task::Waker::from(std::sync::Arc::new(Noop));
+/// #     let mut cx = std::task::Context::from_waker(&waker);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(x) = f.as_mut().poll(&mut cx) {
+/// #             return x;
+/// #         }
+/// #     }
+/// # }
+/// let contact = Contact { satellite: 101, passes: 0 };
+/// let e = block_on(contact.validate_async()).unwrap_err();
+///
+/// assert_eq!(
+///     e.to_string(),
+///     "passes: '0' must be greater than or equal to '1'\nsatellite: satellite 101 does not exist"
+/// );
+/// ```
+pub trait AsyncValidate: Sync {
+    /// Validate the implemented type exhaustively, resolving to all errors once every
+    /// check has completed.
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_;
+}
+
+// a future and, once it has completed, its output. The future is boxed so that it can be
+// pinned without unsafe code
+enum MaybeDone<F: Future> {
+    Pending(Pin<Box<F>>),
+    Done(Option<F::Output>),
+}
+
+impl<F: Future> MaybeDone<F> {
+    fn new(future: F) -> Self {
+        MaybeDone::Pending(Box::pin(future))
+    }
+
+    // returns true once the future has completed
+    fn poll(&mut self, cx: &mut Context<'_>) -> bool {
+        if let MaybeDone::Pending(future) = self {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(output) => *self = MaybeDone::Done(Some(output)),
+                Poll::Pending => return false,
+            }
+        }
+
+        true
+    }
+
+    fn take(&mut self) -> F::Output {
+        match self {
+            MaybeDone::Done(output) => output.take().expect("output already taken"),
+            MaybeDone::Pending(_) => unreachable!("future has not completed"),
+        }
+    }
+}
+
+/// A future polling two futures concurrently, see [`join`]
+#[doc(hidden)]
+pub struct Join<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+// the futures are boxed, so neither they nor their outputs are ever pinned in place
+impl<A: Future, B: Future> Unpin for Join<A, B> {}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        // poll both, so that each can make progress regardless of the other
+        let a = this.a.poll(cx);
+        let b = this.b.poll(cx);
+
+        if a && b {
+            Poll::Ready((this.a.take(), this.b.take()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Await two futures concurrently, used by the derive macro to evaluate the checks of
+/// every field at once
+#[doc(hidden)]
+pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join {
+        a: MaybeDone::new(a),
+        b: MaybeDone::new(b),
+    }
+}
+
+// the indices of the futures of a `JoinAll` which were woken since they were last polled,
+// and the waker of the task awaiting them
+#[derive(Default)]
+struct ReadyQueue {
+    ready: Mutex<Vec<usize>>,
+    parent: Mutex<Option<Waker>>,
+}
+
+// wakes one of the futures of a `JoinAll`, queueing it to be polled
+struct ItemWaker {
+    index: usize,
+    queued: AtomicBool,
+    queue: Arc<ReadyQueue>,
+}
+
+impl Wake for ItemWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if !self.queued.swap(true, Ordering::AcqRel) {
+            lock(&self.queue.ready).push(self.index);
+        }
+
+        if let Some(parent) = lock(&self.queue.parent).as_ref() {
+            parent.wake_by_ref();
+        }
+    }
+}
+
+// a panic while holding the lock leaves the queue consistent, so poisoning is ignored
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A future polling a list of futures concurrently, see [`join_all`]
+#[doc(hidden)]
+pub struct JoinAll<F: Future> {
+    futures: Vec<MaybeDone<F>>,
+    wakers: Vec<(Arc<ItemWaker>, Waker)>,
+    queue: Arc<ReadyQueue>,
+    pending: usize,
+}
+
+impl<F: Future> Unpin for JoinAll<F> {}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        {
+            let mut parent = lock(&this.queue.parent);
+
+            if !parent.as_ref().is_some_and(|x| x.will_wake(cx.waker())) {
+                *parent = Some(cx.waker().clone());
+            }
+        }
+
+        // only the futures woken since the last poll are polled, those woken while polling
+        // wait for the next one
+        let ready = std::mem::take(&mut *lock(&this.queue.ready));
+
+        for i in ready {
+            let (item, waker) = &this.wakers[i];
+            item.queued.store(false, Ordering::Release);
+
+            let future = &mut this.futures[i];
+
+            if matches!(future, MaybeDone::Pending(_))
+                && future.poll(&mut Context::from_waker(waker))
+            {
+                this.pending -= 1;
+            }
+        }
+
+        if this.pending == 0 {
+            Poll::Ready(this.futures.iter_mut().map(MaybeDone::take).collect())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Await every future of an iterator concurrently, resolving to their outputs in order
+///
+/// Every future is started at once, there is no bound on the number in flight. Each is
+/// polled again only once it has been woken.
+#[doc(hidden)]
+pub fn join_all<I>(futures: I) -> JoinAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    let futures: Vec<_> = futures.into_iter().map(MaybeDone::new).collect();
+    let queue = Arc::new(ReadyQueue {
+        ready: Mutex::new((0..futures.len()).collect()),
+        parent: Mutex::new(None),
+    });
+
+    let wakers = (0..futures.len())
+        .map(|index| {
+            let item = Arc::new(ItemWaker {
+                index,
+                queued: AtomicBool::new(true),
+                queue: queue.clone(),
+            });
+
+            (item.clone(), Waker::from(item))
+        })
+        .collect();
+
+    JoinAll {
+        pending: futures.len(),
+        futures,
+        wakers,
+        queue,
+    }
+}
+
+async fn validate_seq_async<'a, I, T>(sequence: I) -> Result<()>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: AsyncValidate + 'a,
+{
+    let results = join_all(sequence.into_iter().map(T::validate_async)).await;
+
+    let mut eb = Error::build();
+
+    for (i, result) in results.into_iter().enumerate() {
+        eb.try_at_index(i, result);
+    }
+
+    eb.build()
+}
+
+async fn validate_map_async<'a, I, K, V>(map: I) -> Result<()>
+where
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    K: std::fmt::Display + 'a,
+    V: AsyncValidate + 'a,
+{
+    let (keys, futures): (Vec<_>, Vec<_>) = map
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.validate_async()))
+        .unzip();
+
+    let results = join_all(futures).await;
+
+    let mut eb = Error::build();
+
+    for (k, result) in keys.into_iter().zip(results) {
+        eb.try_at_named(k, result);
+    }
+
+    eb.build()
+}
+
+async fn validate_result_async<T, E>(result: &std::result::Result<T, E>) -> Result<()>
+where
+    T: AsyncValidate,
+{
+    if let Ok(value) = result {
+        value.validate_async().await
+    } else {
+        Err(Error::new("value is an Error"))
+    }
+}
+
+impl<T> AsyncValidate for Vec<T>
+where
+    T: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+impl<T> AsyncValidate for std::collections::VecDeque<T>
+where
+    T: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+impl<T> AsyncValidate for std::collections::LinkedList<T>
+where
+    T: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+impl<K, V, S> AsyncValidate for std::collections::HashMap<K, V, S>
+where
+    K: std::fmt::Display + Sync,
+    V: AsyncValidate,
+    S: Sync,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_map_async(self)
+    }
+}
+
+impl<K, V> AsyncValidate for std::collections::BTreeMap<K, V>
+where
+    K: std::fmt::Display + Sync,
+    V: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_map_async(self)
+    }
+}
+
+impl<T, S> AsyncValidate for std::collections::HashSet<T, S>
+where
+    T: AsyncValidate,
+    S: Sync,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+impl<T> AsyncValidate for std::collections::BTreeSet<T>
+where
+    T: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+impl<T> AsyncValidate for std::collections::BinaryHeap<T>
+where
+    T: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+#[cfg(feature = "use-indexmap")]
+impl<K, V> AsyncValidate for indexmap::IndexMap<K, V>
+where
+    K: std::fmt::Display + Sync,
+    V: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_map_async(self)
+    }
+}
+
+#[cfg(feature = "use-indexmap")]
+impl<T, S> AsyncValidate for indexmap::IndexSet<T, S>
+where
+    T: AsyncValidate,
+    S: Sync,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+impl<T> AsyncValidate for Option<T>
+where
+    T: AsyncValidate,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_seq_async(self)
+    }
+}
+
+impl<T, E> AsyncValidate for std::result::Result<T, E>
+where
+    T: AsyncValidate,
+    E: Sync,
+{
+    fn validate_async(&self) -> impl Future<Output = Result<()>> + Send + '_ {
+        validate_result_async(self)
+    }
+}
+
+// The derive macro validates nested fields with `Awaited(&field).validate_nested().await`.
+// Method lookup tries `Awaited` before `&Awaited`, so `ByAsync` is picked where the field
+// implements `AsyncValidate`, and `BySync` otherwise.
+
+#[doc(hidden)]
+pub struct Awaited<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ByAsync<'a> {
+    fn validate_nested(self) -> impl Future<Output = Result<()>> + Send + 'a;
+}
+
+impl<'a, T> ByAsync<'a> for Awaited<'a, T>
+where
+    T: AsyncValidate + ?Sized,
+{
+    fn validate_nested(self) -> impl Future<Output = Result<()>> + Send + 'a {
+        self.0.validate_async()
+    }
+}
+
+#[doc(hidden)]
+pub trait BySync {
+    fn validate_nested(self) -> std::future::Ready<Result<()>>;
+}
+
+impl<T> BySync for &Awaited<'_, T>
+where
+    T: Validate + ?Sized,
+{
+    fn validate_nested(self) -> std::future::Ready<Result<()>> {
+        std::future::ready(self.0.validate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    // resolves after being polled the given number of times
+    struct Countdown(usize, &'static str);
+
+    impl Future for Countdown {
+        type Output = &'static str;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if self.0 == 0 {
+                Poll::Ready(self.1)
+            } else {
+                self.0 -= 1;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn poll_count<F: Future>(future: F) -> (usize, F::Output) {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+
+        for polls in 1.. {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return (polls, output);
+            }
+        }
+
+        unreachable!()
+    }
+
+    #[test]
+    fn join_polls_concurrently() {
+        let (polls, output) = poll_count(join(Countdown(2, "a"), Countdown(3, "b")));
+
+        assert_eq!(output, ("a", "b"));
+        assert_eq!(polls, 4);
+    }
+
+    #[test]
+    fn join_all_preserves_order() {
+        let futures = vec![Countdown(3, "a"), Countdown(0, "b"), Countdown(1, "c")];
+        let (polls, output) = poll_count(join_all(futures));
+
+        assert_eq!(output, vec!["a", "b", "c"]);
+        assert_eq!(polls, 4);
+
+        let (_, output) = poll_count(join_all(Vec::<Countdown>::new()));
+        assert!(output.is_empty());
+    }
+
+    // counts how many times a future is polled
+    struct Polled<'a>(&'a Cell<usize>, Countdown);
+
+    impl Future for Polled<'_> {
+        type Output = &'static str;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            self.0.set(self.0.get() + 1);
+            Pin::new(&mut self.1).poll(cx)
+        }
+    }
+
+    #[test]
+    fn join_all_polls_woken_futures() {
+        let polls: Vec<_> = (0..3).map(|_| Cell::new(0)).collect();
+        let futures = vec![
+            Polled(&polls[0], Countdown(5, "a")),
+            Polled(&polls[1], Countdown(0, "b")),
+            Polled(&polls[2], Countdown(1, "c")),
+        ];
+
+        let (_, output) = poll_count(join_all(futures));
+
+        assert_eq!(output, vec!["a", "b", "c"]);
+        assert_eq!(
+            polls.iter().map(Cell::get).collect::<Vec<_>>(),
+            vec![6, 1, 2]
+        );
+    }
+}
@@ -0,0 +1,497 @@
+use std::{borrow::Cow, collections::BTreeMap, collections::HashMap, fmt};
+
+use crate::{Error, Param, Reason};
+
+/// A source of translated messages for validation failures.
+///
+/// Catalogs render a [`Reason`] from its code and parameters, returning `None` when
+/// they have no message for the code in the requested locale.
+pub trait MessageCatalog {
+    /// Render the message for the given reason in the given locale
+    fn render(&self, reason: &Reason, locale: &str) -> Option<String>;
+}
+
+/// Substitute `{name}` placeholders in a template with the given parameters.
+///
+/// `{{` and `}}` are escapes for literal braces, unknown placeholders are left untouched.
+///
+/// ```
+/// # use validatron::catalog::render_template;
+/// # use validatron::Param;
+/// let params = [("min".into(), Param::UInt(42))].into();
+/// assert_eq!(render_template("at least {min} {{km}}", &params), "at least 42 {km}");
+/// ```
+pub fn render_template(template: &str, params: &BTreeMap<Cow<'static, str>, Param>) -> String {
+    let mut out = String::with_capacity(template.len());
+
+    // writing to a string cannot fail
+    let _ = write_template(&mut out, template, params);
+
+    out
+}
+
+// renders a template as `render_template` does, directly to a writer
+pub(crate) fn write_template(
+    out: &mut impl fmt::Write,
+    template: &str,
+    params: &BTreeMap<Cow<'static, str>, Param>,
+) -> fmt::Result {
+    let mut rest = template;
+
+    while let Some(i) = rest.find(['{', '}']) {
+        out.write_str(&rest[..i])?;
+        rest = &rest[i..];
+
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            out.write_str(&rest[..1])?;
+            rest = &rest[2..];
+            continue;
+        }
+
+        let param = rest
+            .find('}')
+            .filter(|_| rest.starts_with('{'))
+            .and_then(|end| params.get(&rest[1..end]).map(|param| (end, param)));
+
+        match param {
+            Some((end, param)) => {
+                write!(out, "{}", param)?;
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.write_str(&rest[..1])?;
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.write_str(rest)
+}
+
+/// The English messages used by the inbuilt validators, keyed by code
+const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    ("required", "Option is required to have a value"),
+    ("equal", "'{value}' must equal '{equal}'"),
+    ("one_of", "'{value}' must be one of {one_of}"),
+    ("none_of", "'{value}' must not be one of {none_of}"),
+    ("min", "'{value}' must be greater than or equal to '{min}'"),
+    ("max", "'{value}' must be less than or equal to '{max}'"),
+    (
+        "exclusive_min",
+        "'{value}' must be greater than '{exclusive_min}'",
+    ),
+    (
+        "exclusive_max",
+        "'{value}' must be less than '{exclusive_max}'",
+    ),
+    ("range", "'{value}' must be in {range}"),
+    ("not_nan", "value must not be NaN"),
+    ("finite", "'{value}' must be a finite number"),
+    (
+        "multiple_of",
+        "'{value}' must be a multiple of '{multiple_of}'",
+    ),
+    (
+        "max_decimal_places",
+        "'{value}' must have at most {max_decimal_places} decimal places",
+    ),
+    (
+        "min_len",
+        "sequence does not have enough elements, it has {len} but the minimum is {min_len}",
+    ),
+    (
+        "max_len",
+        "sequence has too many elements, it has {len} but the maximum is {max_len}",
+    ),
+    (
+        "exact_len",
+        "sequence must have exactly {exact_len} elements, it has {len}",
+    ),
+    (
+        "len_range",
+        "sequence must have a number of elements within {len_range}, it has {len}",
+    ),
+    (
+        "min_text_len",
+        "text is too short, it has {len} {unit} but the minimum is {min_len}",
+    ),
+    (
+        "max_text_len",
+        "text is too long, it has {len} {unit} but the maximum is {max_len}",
+    ),
+    (
+        "exact_text_len",
+        "text must have exactly {exact_len} {unit}, it has {len}",
+    ),
+    (
+        "text_len_range",
+        "text must have a length within {len_range} {unit}, it has {len}",
+    ),
+    ("non_empty", "value must not be empty"),
+    ("unique", "item is a duplicate of the item at index {first}"),
+    (
+        "sorted",
+        "item is out of order, it must not be less than the item before it",
+    ),
+    (
+        "strictly_sorted",
+        "item is out of order, it must be greater than the item before it",
+    ),
+    ("contains", "collection must contain {contains}"),
+    ("not_contains", "collection must not contain {not_contains}"),
+    (
+        "pattern",
+        "'{value}' does not match the pattern '{pattern}'",
+    ),
+    ("email", "'{value}' is not a valid email address"),
+    ("url", "'{value}' is not a valid URL"),
+    (
+        "url_scheme",
+        "'{value}' must be a URL using one of the schemes {schemes}",
+    ),
+    ("hostname", "'{value}' is not a valid hostname"),
+    ("ip", "'{value}' is not a valid IP address"),
+    ("ipv4", "'{value}' is not a valid IPv4 address"),
+    ("ipv6", "'{value}' is not a valid IPv6 address"),
+    ("cidr", "'{value}' is not a valid network in CIDR notation"),
+    ("predicate", "Predicate \"{predicate}\" failed"),
+    (
+        "eq_field",
+        "{field} ('{value}') must be equal to {other_field} ('{other}')",
+    ),
+    (
+        "gt_field",
+        "{field} ('{value}') must be greater than {other_field} ('{other}')",
+    ),
+    (
+        "gte_field",
+        "{field} ('{value}') must be greater than or equal to {other_field} ('{other}')",
+    ),
+    (
+        "lt_field",
+        "{field} ('{value}') must be less than {other_field} ('{other}')",
+    ),
+    (
+        "lte_field",
+        "{field} ('{value}') must be less than or equal to {other_field} ('{other}')",
+    ),
+];
+
+// the template for the message of an inbuilt validator
+pub(crate) fn default_template(code: &str) -> Option<&'static str> {
+    DEFAULT_TEMPLATES
+        .iter()
+        .find(|(x, _)| *x == code)
+        .map(|(_, template)| *template)
+}
+
+/// The English messages produced by the inbuilt validators, for any locale.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultCatalog;
+
+impl MessageCatalog for DefaultCatalog {
+    fn render(&self, reason: &Reason, _locale: &str) -> Option<String> {
+        default_template(reason.code()).map(|template| render_template(template, reason.params()))
+    }
+}
+
+/// A catalog of message templates keyed by locale and code.
+///
+/// Templates use `{name}` placeholders for the parameters of a failure, see
+/// [`render_template`]. Locales such as `de-CH` fall back to their base language `de`
+/// when they have no template for a code.
+///
+/// ```
+/// # use validatron::catalog::TemplateCatalog;
+/// # use validatron::validators::min;
+/// let catalog = TemplateCatalog::new()
+///     .with_template("de", "min", "'{value}' muss mindestens '{min}' sein");
+///
+/// let e = min(&36, 42).unwrap_err().localize(&catalog, "de-CH");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "use-toml", derive(serde::Deserialize), serde(transparent))]
+pub struct TemplateCatalog {
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl TemplateCatalog {
+    /// Construct an empty catalog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a template for a code in the given locale
+    pub fn insert(
+        &mut self,
+        locale: impl Into<String>,
+        code: impl Into<String>,
+        template: impl Into<String>,
+    ) -> &mut Self {
+        self.locales
+            .entry(locale.into())
+            .or_default()
+            .insert(code.into(), template.into());
+
+        self
+    }
+
+    /// Add a template for a code in the given locale
+    pub fn with_template(
+        mut self,
+        locale: impl Into<String>,
+        code: impl Into<String>,
+        template: impl Into<String>,
+    ) -> Self {
+        self.insert(locale, code, template);
+        self
+    }
+
+    /// Load a catalog from a TOML document with a table per locale mapping codes to
+    /// templates
+    ///
+    /// ```
+    /// # use validatron::catalog::TemplateCatalog;
+    /// let catalog = TemplateCatalog::from_toml(r#"
+    /// [de]
+    /// min = "'{value}' muss mindestens '{min}' sein"
+    /// required = "Wert ist erforderlich"
+    /// "#).unwrap();
+    /// ```
+    #[cfg(feature = "use-toml")]
+    pub fn from_toml(document: &str) -> std::result::Result<Self, toml::de::Error> {
+        toml::from_str(document)
+    }
+
+    fn template(&self, locale: &str, code: &str) -> Option<&str> {
+        self.locales
+            .get(locale)
+            .and_then(|templates| templates.get(code))
+            .map(String::as_str)
+    }
+}
+
+impl MessageCatalog for TemplateCatalog {
+    fn render(&self, reason: &Reason, locale: &str) -> Option<String> {
+        let code = reason.code();
+
+        self.template(locale, code)
+            .or_else(|| {
+                let language = locale.split(['-', '_']).next()?;
+                self.template(language, code)
+            })
+            .map(|template| render_template(template, reason.params()))
+    }
+}
+
+impl<C> MessageCatalog for &C
+where
+    C: MessageCatalog + ?Sized,
+{
+    fn render(&self, reason: &Reason, locale: &str) -> Option<String> {
+        (**self).render(reason, locale)
+    }
+}
+
+impl Error {
+    /// Rewrite every message in the error using the given catalog and locale.
+    ///
+    /// Reasons the catalog has no message for keep their existing message, as do those
+    /// given a message with [`Reason::with_message`], such as by the `message` attribute of
+    /// the derive macro.
+    ///
+    /// ```
+    /// # use validatron::catalog::TemplateCatalog;
+    /// # use validatron::{Error, Reason};
+    /// let catalog = TemplateCatalog::new().with_template("de", "required", "ist erforderlich");
+    ///
+    /// let e = Error::build()
+    ///     .at_named("a", Reason::new("required", "Option is required to have a value"))
+    ///     .build()
+    ///     .unwrap_err()
+    ///     .localize(&catalog, "de");
+    /// ```
+    pub fn localize<C>(mut self, catalog: &C, locale: &str) -> Self
+    where
+        C: MessageCatalog + ?Sized,
+    {
+        self.localize_in_place(catalog, locale);
+        self
+    }
+
+    fn localize_in_place<C>(&mut self, catalog: &C, locale: &str)
+    where
+        C: MessageCatalog + ?Sized,
+    {
+        match self {
+            Error::Unstructured(reasons) => {
+                for reason in reasons.iter_mut().filter(|r| !r.has_custom_message()) {
+                    if let Some(message) = catalog.render(reason, locale) {
+                        reason.set_message(message);
+                    }
+                }
+            }
+            Error::Structured(errors) => {
+                for error in errors.values_mut() {
+                    error.localize_in_place(catalog, locale);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators;
+
+    fn reason(e: Error) -> Reason {
+        match e {
+            Error::Unstructured(mut reasons) => reasons.remove(0),
+            // the failures of collection validators are reported at the offending items
+            Error::Structured(_) => e.flatten().next().unwrap().1.clone(),
+        }
+    }
+
+    #[test]
+    fn default_catalog_matches_validators() {
+        let errors = vec![
+            validators::is_required::<u8>(&None).unwrap_err(),
+            validators::is_equal(&1.5, 2.).unwrap_err(),
+            validators::one_of(&"off", &["nominal", "safe"]).unwrap_err(),
+            validators::none_of_ignore_case("Test", &["test"]).unwrap_err(),
+            validators::min(&-3, 5).unwrap_err(),
+            validators::max(&"b", "a").unwrap_err(),
+            validators::exclusive_min(&1, 1).unwrap_err(),
+            validators::exclusive_max(&1.5, 1.0).unwrap_err(),
+            validators::range(&-1, 0..360).unwrap_err(),
+            validators::not_nan(&f64::NAN).unwrap_err(),
+            validators::finite(&f32::INFINITY).unwrap_err(),
+            validators::multiple_of(&7, 5).unwrap_err(),
+            validators::max_decimal_places(&0.125, 2).unwrap_err(),
+            validators::is_min_length(vec![1], 2).unwrap_err(),
+            validators::is_max_length(vec![1, 2], 1).unwrap_err(),
+            validators::is_exact_length(vec![1, 2], 1).unwrap_err(),
+            validators::is_length_in_range(vec![1, 2], 3..).unwrap_err(),
+            validators::is_min_text_length("a", 2, validators::Unit::Chars).unwrap_err(),
+            validators::is_max_text_length("ab", 1, validators::Unit::Bytes).unwrap_err(),
+            validators::is_exact_text_length("ab", 1, validators::Unit::Chars).unwrap_err(),
+            validators::is_text_length_in_range("ab", 3..5, validators::Unit::Chars).unwrap_err(),
+            validators::non_empty("").unwrap_err(),
+            validators::unique(&[1, 1]).unwrap_err(),
+            validators::sorted(&[2, 1]).unwrap_err(),
+            validators::strictly_sorted(&[1, 1]).unwrap_err(),
+            validators::contains(&[1], 2).unwrap_err(),
+            validators::not_contains(&["a"], "a").unwrap_err(),
+            validators::email("a@").unwrap_err(),
+            validators::url("a", &[]).unwrap_err(),
+            validators::url("ftp://a", &["http", "https"]).unwrap_err(),
+            validators::hostname("a_b").unwrap_err(),
+            validators::ip("a").unwrap_err(),
+            validators::ipv4("::1").unwrap_err(),
+            validators::ipv6("127.0.0.1").unwrap_err(),
+            validators::cidr("127.0.0.1").unwrap_err(),
+        ];
+
+        for e in errors {
+            let r = reason(e);
+            assert_eq!(
+                DefaultCatalog.render(&r, "en"),
+                Some(r.message().into_owned()),
+                "{}",
+                r.code()
+            );
+        }
+    }
+
+    #[test]
+    fn template_rendering() {
+        let params = [
+            ("a".into(), Param::Int(-1)),
+            ("b".into(), Param::Str("x".into())),
+        ]
+        .into();
+
+        assert_eq!(render_template("{a} {b}", &params), "-1 x");
+        assert_eq!(render_template("{{a}} {c} }", &params), "{a} {c} }");
+        assert_eq!(render_template("{a", &params), "{a");
+        assert_eq!(render_template("é{b}ü", &params), "éxü");
+    }
+
+    #[test]
+    fn localize_tree() {
+        let catalog = TemplateCatalog::new()
+            .with_template("de", "min", "muss mindestens {min} sein")
+            .with_template("de-AT", "min", "muass mindestens {min} sei");
+
+        let e = Error::build()
+            .try_at_named("a", validators::min(&1, 2))
+            .try_at_named(
+                "b",
+                Error::build()
+                    .try_at_index(0, validators::min(&1, 3))
+                    .at_index(0, "custom message")
+                    .build(),
+            )
+            .build()
+            .unwrap_err();
+
+        let de = e.localize(&catalog, "de-CH");
+
+        let expected = Error::build()
+            .try_at_named(
+                "a",
+                Err(reason(validators::min(&1, 2).unwrap_err())
+                    .with_message("muss mindestens 2 sein")
+                    .into()),
+            )
+            .try_at_named(
+                "b",
+                Error::build()
+                    .try_at_index(
+                        0,
+                        Err(reason(validators::min(&1, 3).unwrap_err())
+                            .with_message("muss mindestens 3 sein")
+                            .into()),
+                    )
+                    .at_index(0, "custom message")
+                    .build(),
+            )
+            .build()
+            .unwrap_err();
+
+        assert_eq!(de, expected);
+
+        let at = de.localize(&catalog, "de-AT");
+        match at {
+            Error::Structured(x) => assert_eq!(
+                reason(x.into_iter().next().unwrap().1).message(),
+                "muass mindestens 2 sei"
+            ),
+            Error::Unstructured(_) => panic!("should not happen"),
+        }
+    }
+
+    #[cfg(feature = "use-toml")]
+    #[test]
+    fn toml_catalog() {
+        let catalog = TemplateCatalog::from_toml(
+            r#"
+            [de]
+            required = "Wert ist erforderlich"
+
+            [fr]
+            required = "valeur requise"
+            "#,
+        )
+        .unwrap();
+
+        let e = validators::is_required::<u8>(&None).unwrap_err();
+
+        assert_eq!(
+            reason(e.localize(&catalog, "fr")).message(),
+            "valeur requise"
+        );
+        assert!(TemplateCatalog::from_toml("[de]\nrequired = 1").is_err());
+    }
+}
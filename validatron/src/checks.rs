@@ -0,0 +1,663 @@
+// each predicate holds exactly when the validator of the same name passes, without
+// allocating an error when it does not, nor allocating at all except in `unique`
+
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::ops::{Bound, RangeBounds};
+
+use crate::validators::Unit;
+use crate::{formats, length, Collection, HasLength, Number};
+
+/// Whether an option has a value, see [`is_required`](crate::validators::is_required)
+///
+/// ```
+/// # use validatron::checks::is_required;
+/// assert!(is_required(&Some(42)));
+/// assert!(!is_required::<i64>(&None));
+/// ```
+pub fn is_required<T>(value: &Option<T>) -> bool {
+    value.is_some()
+}
+
+/// Whether values are equal, see [`is_equal`](crate::validators::is_equal)
+///
+/// ```
+/// # use validatron::checks::is_equal;
+/// assert!(is_equal(&42, 42));
+/// assert!(!is_equal(&1.0, 2.0));
+/// ```
+pub fn is_equal<L, R>(value: &L, other: R) -> bool
+where
+    L: PartialEq<R>,
+{
+    *value == other
+}
+
+/// Whether a value equals one of a list of allowed values, see
+/// [`one_of`](crate::validators::one_of)
+///
+/// ```
+/// # use validatron::checks::one_of;
+/// assert!(one_of(&String::from("safe"), &["nominal", "safe"]));
+/// assert!(!one_of(&3, &[1, 2]));
+/// ```
+pub fn one_of<T, A>(value: &T, allowed: &[A]) -> bool
+where
+    T: PartialEq<A>,
+{
+    allowed.iter().any(|x| *value == *x)
+}
+
+/// Whether a value equals none of a list of forbidden values, see
+/// [`none_of`](crate::validators::none_of)
+pub fn none_of<T, A>(value: &T, forbidden: &[A]) -> bool
+where
+    T: PartialEq<A>,
+{
+    !one_of(value, forbidden)
+}
+
+// compares the lowercase forms of the characters, which unlike `str::to_lowercase` does not
+// allocate
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
+/// Whether text equals one of a list of allowed values ignoring case, see
+/// [`one_of_ignore_case`](crate::validators::one_of_ignore_case)
+pub fn one_of_ignore_case<T, A>(value: &T, allowed: &[A]) -> bool
+where
+    T: AsRef<str> + ?Sized,
+    A: AsRef<str>,
+{
+    allowed
+        .iter()
+        .any(|x| eq_ignore_case(value.as_ref(), x.as_ref()))
+}
+
+/// Whether text equals none of a list of forbidden values ignoring case, see
+/// [`none_of_ignore_case`](crate::validators::none_of_ignore_case)
+pub fn none_of_ignore_case<T, A>(value: &T, forbidden: &[A]) -> bool
+where
+    T: AsRef<str> + ?Sized,
+    A: AsRef<str>,
+{
+    !one_of_ignore_case(value, forbidden)
+}
+
+/// Whether a value is at least a minimum, see [`min`](crate::validators::min)
+///
+/// ```
+/// # use validatron::checks::min;
+/// assert!(min(&42, 0));
+/// assert!(!min(&1.0, 2.0));
+/// ```
+pub fn min<L, R>(value: &L, min: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    *value >= min
+}
+
+/// Whether an optional value is none or at least a minimum, see
+/// [`option_min`](crate::validators::option_min)
+pub fn option_min<L, R>(value: &Option<L>, min_value: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    value.as_ref().map_or(true, |x| min(x, min_value))
+}
+
+/// Whether a value is at most a maximum, see [`max`](crate::validators::max)
+///
+/// ```
+/// # use validatron::checks::max;
+/// assert!(max(&42, 128));
+/// assert!(!max(&2.0, 1.0));
+/// ```
+pub fn max<L, R>(value: &L, max: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    *value <= max
+}
+
+/// Whether an optional value is none or at most a maximum, see
+/// [`option_max`](crate::validators::option_max)
+pub fn option_max<L, R>(value: &Option<L>, max_value: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    value.as_ref().map_or(true, |x| max(x, max_value))
+}
+
+/// Whether the value of a field equals that of another, see
+/// [`eq_field`](crate::validators::eq_field)
+pub fn eq_field<L, R>(value: &L, other: &R) -> bool
+where
+    L: PartialEq<R> + ?Sized,
+    R: ?Sized,
+{
+    *value == *other
+}
+
+/// Whether the value of a field is greater than that of another, see
+/// [`gt_field`](crate::validators::gt_field)
+pub fn gt_field<L, R>(value: &L, other: &R) -> bool
+where
+    L: PartialOrd<R> + ?Sized,
+    R: ?Sized,
+{
+    *value > *other
+}
+
+/// Whether the value of a field is greater than or equal to that of another, see
+/// [`gte_field`](crate::validators::gte_field)
+pub fn gte_field<L, R>(value: &L, other: &R) -> bool
+where
+    L: PartialOrd<R> + ?Sized,
+    R: ?Sized,
+{
+    *value >= *other
+}
+
+/// Whether the value of a field is less than that of another, see
+/// [`lt_field`](crate::validators::lt_field)
+pub fn lt_field<L, R>(value: &L, other: &R) -> bool
+where
+    L: PartialOrd<R> + ?Sized,
+    R: ?Sized,
+{
+    *value < *other
+}
+
+/// Whether the value of a field is less than or equal to that of another, see
+/// [`lte_field`](crate::validators::lte_field)
+pub fn lte_field<L, R>(value: &L, other: &R) -> bool
+where
+    L: PartialOrd<R> + ?Sized,
+    R: ?Sized,
+{
+    *value <= *other
+}
+
+/// Whether a value is greater than a value, see
+/// [`exclusive_min`](crate::validators::exclusive_min)
+pub fn exclusive_min<L, R>(value: &L, min: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    *value > min
+}
+
+/// Whether an optional value is none or greater than a value, see
+/// [`option_exclusive_min`](crate::validators::option_exclusive_min)
+pub fn option_exclusive_min<L, R>(value: &Option<L>, min_value: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    value.as_ref().map_or(true, |x| exclusive_min(x, min_value))
+}
+
+/// Whether a value is less than a value, see
+/// [`exclusive_max`](crate::validators::exclusive_max)
+pub fn exclusive_max<L, R>(value: &L, max: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    *value < max
+}
+
+/// Whether an optional value is none or less than a value, see
+/// [`option_exclusive_max`](crate::validators::option_exclusive_max)
+pub fn option_exclusive_max<L, R>(value: &Option<L>, max_value: R) -> bool
+where
+    L: PartialOrd<R>,
+{
+    value.as_ref().map_or(true, |x| exclusive_max(x, max_value))
+}
+
+/// Whether a value is within a range, see [`range`](crate::validators::range)
+///
+/// ```
+/// # use validatron::checks::range;
+/// assert!(range(&0, 0..360));
+/// assert!(!range(&360, 0..360));
+/// ```
+pub fn range<L, R, B>(value: &L, range: B) -> bool
+where
+    L: PartialOrd<R>,
+    B: RangeBounds<R>,
+{
+    // incomparable values such as NaN are outside of every bound
+    let above_start = match range.start_bound() {
+        Bound::Included(min) => *value >= *min,
+        Bound::Excluded(min) => *value > *min,
+        Bound::Unbounded => true,
+    };
+
+    let below_end = match range.end_bound() {
+        Bound::Included(max) => *value <= *max,
+        Bound::Excluded(max) => *value < *max,
+        Bound::Unbounded => true,
+    };
+
+    above_start && below_end
+}
+
+/// Whether an optional value is none or within a range, see
+/// [`option_range`](crate::validators::option_range)
+pub fn option_range<L, R, B>(value: &Option<L>, bounds: B) -> bool
+where
+    L: PartialOrd<R>,
+    B: RangeBounds<R>,
+{
+    value.as_ref().map_or(true, |x| range(x, bounds))
+}
+
+/// Whether a value is comparable with itself, see [`not_nan`](crate::validators::not_nan)
+#[allow(clippy::eq_op)]
+pub fn not_nan<T>(value: &T) -> bool
+where
+    T: PartialOrd,
+{
+    value.partial_cmp(value).is_some()
+}
+
+/// Whether a number is neither infinite nor NaN, see [`finite`](crate::validators::finite)
+pub fn finite<T>(value: &T) -> bool
+where
+    T: Number,
+{
+    value.is_finite()
+}
+
+/// Whether a number is a multiple of a factor, see
+/// [`multiple_of`](crate::validators::multiple_of)
+pub fn multiple_of<T>(value: &T, factor: T) -> bool
+where
+    T: Number,
+{
+    value.is_multiple_of(factor)
+}
+
+/// Whether a number has at most a number of decimal places, see
+/// [`max_decimal_places`](crate::validators::max_decimal_places)
+pub fn max_decimal_places<T>(value: &T, places: usize) -> bool
+where
+    T: Number,
+{
+    value.decimal_places() <= places
+}
+
+/// Whether a sequence has at least a number of elements, see
+/// [`is_min_length`](crate::validators::is_min_length)
+///
+/// ```
+/// # use validatron::checks::is_min_length;
+/// assert!(is_min_length(&vec![1, 2, 3], 3));
+/// assert!(!is_min_length(&vec![1, 2, 3], 4));
+/// ```
+pub fn is_min_length<C>(iterable: C, min_length: usize) -> bool
+where
+    C: IntoIterator,
+{
+    let mut iter = iterable.into_iter();
+
+    match length::exact_size(&iter) {
+        Some(len) => len >= min_length,
+        // stops counting once the minimum is reached
+        None => min_length == 0 || iter.nth(min_length - 1).is_some(),
+    }
+}
+
+/// Whether a sequence has at most a number of elements, see
+/// [`is_max_length`](crate::validators::is_max_length)
+///
+/// ```
+/// # use validatron::checks::is_max_length;
+/// assert!(is_max_length(&vec![1, 2, 3], 3));
+/// assert!(!is_max_length(&vec![1, 2, 3], 2));
+/// ```
+pub fn is_max_length<C>(iterable: C, max_length: usize) -> bool
+where
+    C: IntoIterator,
+{
+    let mut iter = iterable.into_iter();
+
+    match length::exact_size(&iter) {
+        Some(len) => len <= max_length,
+        // stops counting once the maximum is exceeded
+        None => iter.nth(max_length).is_none(),
+    }
+}
+
+/// Whether a sequence has exactly a number of elements, see
+/// [`is_exact_length`](crate::validators::is_exact_length)
+pub fn is_exact_length<C>(iterable: C, exact_length: usize) -> bool
+where
+    C: IntoIterator,
+{
+    let iter = iterable.into_iter();
+
+    match length::exact_size(&iter) {
+        Some(len) => len == exact_length,
+        // stops counting once the length is exceeded
+        None => iter.take(exact_length.saturating_add(1)).count() == exact_length,
+    }
+}
+
+/// Whether the length of a sequence is within a range, see
+/// [`is_length_in_range`](crate::validators::is_length_in_range)
+pub fn is_length_in_range<C, R>(iterable: C, range: R) -> bool
+where
+    C: IntoIterator,
+    R: RangeBounds<usize>,
+{
+    range.contains(&length::count(iterable))
+}
+
+/// Whether text is at least a certain length, see
+/// [`is_min_text_length`](crate::validators::is_min_text_length)
+pub fn is_min_text_length(value: &str, min_length: usize, unit: Unit) -> bool {
+    unit.count(value) >= min_length
+}
+
+/// Whether text is at most a certain length, see
+/// [`is_max_text_length`](crate::validators::is_max_text_length)
+pub fn is_max_text_length(value: &str, max_length: usize, unit: Unit) -> bool {
+    unit.count(value) <= max_length
+}
+
+/// Whether text has exactly a certain length, see
+/// [`is_exact_text_length`](crate::validators::is_exact_text_length)
+pub fn is_exact_text_length(value: &str, exact_length: usize, unit: Unit) -> bool {
+    unit.count(value) == exact_length
+}
+
+/// Whether the length of text is within a range, see
+/// [`is_text_length_in_range`](crate::validators::is_text_length_in_range)
+pub fn is_text_length_in_range<R>(value: &str, range: R, unit: Unit) -> bool
+where
+    R: RangeBounds<usize>,
+{
+    range.contains(&unit.count(value))
+}
+
+/// Whether a collection or text has any items, see
+/// [`non_empty`](crate::validators::non_empty)
+pub fn non_empty<C>(value: C) -> bool
+where
+    C: HasLength,
+{
+    value.length() > 0
+}
+
+// collections of at most this many items are checked for duplicates by comparing every
+// pair, rather than by hashing them into a set
+const PAIRWISE_UNIQUE: usize = 16;
+
+/// Whether the items of a collection are unique, see [`unique`](crate::validators::unique)
+///
+/// Collections of more than a few items are hashed into a set, which allocates.
+///
+/// ```
+/// # use validatron::checks::unique;
+/// assert!(unique(&vec![1, 2, 3]));
+/// assert!(!unique(&vec![1, 2, 1]));
+/// ```
+pub fn unique<C>(value: &C) -> bool
+where
+    C: Collection + ?Sized,
+    C::Item: Hash + Eq,
+{
+    if value.items().nth(PAIRWISE_UNIQUE).is_none() {
+        return value
+            .items()
+            .enumerate()
+            .all(|(i, x)| value.items().take(i).all(|y| x != y));
+    }
+
+    let mut seen = HashSet::new();
+    value.items().all(|x| seen.insert(x))
+}
+
+// the index of the first item whose key is out of order with the key of the item before it,
+// where keys which cannot be compared are out of order
+pub(crate) fn first_unsorted<'a, I, T, F, K>(items: I, key: F, strict: bool) -> Option<usize>
+where
+    I: Iterator<Item = &'a T>,
+    T: 'a + ?Sized,
+    F: FnMut(&'a T) -> K,
+    K: PartialOrd,
+{
+    let mut items = items.map(key).enumerate();
+    let (_, mut previous) = items.next()?;
+
+    for (i, x) in items {
+        let in_order = if strict { previous < x } else { previous <= x };
+
+        if !in_order {
+            return Some(i);
+        }
+
+        previous = x;
+    }
+
+    None
+}
+
+/// Whether the items of a collection are in ascending order, see
+/// [`sorted`](crate::validators::sorted)
+///
+/// ```
+/// # use validatron::checks::sorted;
+/// assert!(sorted(&vec![1, 2, 2, 3]));
+/// assert!(!sorted(&vec![1, 3, 2]));
+/// ```
+pub fn sorted<C>(value: &C) -> bool
+where
+    C: Collection + ?Sized,
+    C::Item: PartialOrd,
+{
+    first_unsorted(value.items(), |x| x, false).is_none()
+}
+
+/// Whether the items of a collection are in strictly ascending order, see
+/// [`strictly_sorted`](crate::validators::strictly_sorted)
+pub fn strictly_sorted<C>(value: &C) -> bool
+where
+    C: Collection + ?Sized,
+    C::Item: PartialOrd,
+{
+    first_unsorted(value.items(), |x| x, true).is_none()
+}
+
+/// Whether the items of a collection are in ascending order of a key, see
+/// [`sorted_by_key`](crate::validators::sorted_by_key)
+pub fn sorted_by_key<C, F, K>(value: &C, key: F) -> bool
+where
+    C: Collection + ?Sized,
+    F: FnMut(&C::Item) -> K,
+    K: PartialOrd,
+{
+    first_unsorted(value.items(), key, false).is_none()
+}
+
+/// Whether the items of a collection are in strictly ascending order of a key, see
+/// [`strictly_sorted_by_key`](crate::validators::strictly_sorted_by_key)
+pub fn strictly_sorted_by_key<C, F, K>(value: &C, key: F) -> bool
+where
+    C: Collection + ?Sized,
+    F: FnMut(&C::Item) -> K,
+    K: PartialOrd,
+{
+    first_unsorted(value.items(), key, true).is_none()
+}
+
+/// Whether a collection contains an item, see [`contains`](crate::validators::contains)
+///
+/// ```
+/// # use validatron::checks::contains;
+/// assert!(contains(&vec![String::from("GS-1")], "GS-1"));
+/// assert!(!contains(&vec![1, 2], 3));
+/// ```
+pub fn contains<C, Q>(value: &C, item: Q) -> bool
+where
+    C: Collection + ?Sized,
+    C::Item: PartialEq<Q>,
+{
+    value.items().any(|x| *x == item)
+}
+
+/// Whether a collection does not contain an item, see
+/// [`not_contains`](crate::validators::not_contains)
+pub fn not_contains<C, Q>(value: &C, item: Q) -> bool
+where
+    C: Collection + ?Sized,
+    C::Item: PartialEq<Q>,
+{
+    !contains(value, item)
+}
+
+/// Whether text matches a regular expression, see
+/// [`matches_pattern`](crate::validators::matches_pattern)
+#[cfg(feature = "use-regex")]
+pub fn matches_pattern<T>(value: &T, pattern: &regex::Regex) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    pattern.is_match(value.as_ref())
+}
+
+/// Whether text, if present, matches a regular expression, see
+/// [`option_matches_pattern`](crate::validators::option_matches_pattern)
+#[cfg(feature = "use-regex")]
+pub fn option_matches_pattern<T>(value: &Option<T>, pattern: &regex::Regex) -> bool
+where
+    T: AsRef<str>,
+{
+    value
+        .as_ref()
+        .map_or(true, |value| pattern.is_match(value.as_ref()))
+}
+
+// schemes are case insensitive, and any is allowed when none are given
+fn is_allowed_scheme(scheme: &str, schemes: &[&str]) -> bool {
+    schemes.is_empty() || schemes.iter().any(|x| x.eq_ignore_ascii_case(scheme))
+}
+
+/// Whether text is an email address, see [`email`](crate::validators::email)
+pub fn email<T>(value: &T) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    formats::is_email(value.as_ref())
+}
+
+/// Whether text, if present, is an email address
+pub fn option_email<T>(value: &Option<T>) -> bool
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(true, email)
+}
+
+/// Whether text is a URL using one of the given schemes, see [`url`](crate::validators::url)
+pub fn url<T>(value: &T, schemes: &[&str]) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    formats::url_scheme(value.as_ref()).is_some_and(|x| is_allowed_scheme(x, schemes))
+}
+
+/// Whether text, if present, is a URL using one of the given schemes
+pub fn option_url<T>(value: &Option<T>, schemes: &[&str]) -> bool
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(true, |value| url(value, schemes))
+}
+
+/// Whether text is a hostname, see [`hostname`](crate::validators::hostname)
+pub fn hostname<T>(value: &T) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    formats::is_hostname(value.as_ref())
+}
+
+/// Whether text, if present, is a hostname
+pub fn option_hostname<T>(value: &Option<T>) -> bool
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(true, hostname)
+}
+
+/// Whether text is an IPv4 or IPv6 address, see [`ip`](crate::validators::ip)
+pub fn ip<T>(value: &T) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    value.as_ref().parse::<IpAddr>().is_ok()
+}
+
+/// Whether text, if present, is an IPv4 or IPv6 address
+pub fn option_ip<T>(value: &Option<T>) -> bool
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(true, ip)
+}
+
+/// Whether text is an IPv4 address, see [`ipv4`](crate::validators::ipv4)
+pub fn ipv4<T>(value: &T) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    value.as_ref().parse::<Ipv4Addr>().is_ok()
+}
+
+/// Whether text, if present, is an IPv4 address
+pub fn option_ipv4<T>(value: &Option<T>) -> bool
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(true, ipv4)
+}
+
+/// Whether text is an IPv6 address, see [`ipv6`](crate::validators::ipv6)
+pub fn ipv6<T>(value: &T) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    value.as_ref().parse::<Ipv6Addr>().is_ok()
+}
+
+/// Whether text, if present, is an IPv6 address
+pub fn option_ipv6<T>(value: &Option<T>) -> bool
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(true, ipv6)
+}
+
+/// Whether text is a network in CIDR notation, see [`cidr`](crate::validators::cidr)
+pub fn cidr<T>(value: &T) -> bool
+where
+    T: AsRef<str> + ?Sized,
+{
+    formats::is_cidr(value.as_ref())
+}
+
+/// Whether text, if present, is a network in CIDR notation
+pub fn option_cidr<T>(value: &Option<T>) -> bool
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(true, cidr)
+}
@@ -0,0 +1,83 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+/// Collections whose items can be searched and ordered, checked by the collection
+/// validators such as [`unique`](crate::validators::unique).
+///
+/// The items of a map are its keys. Items are visited in iteration order, which is only
+/// meaningful for ordered collections such as sequences, `BTreeSet` and `IndexMap`.
+///
+/// ```
+/// # use validatron::Collection;
+/// let x = std::collections::BTreeMap::from([(2, "b"), (1, "a")]);
+/// assert_eq!(x.items().collect::<Vec<_>>(), [&1, &2]);
+/// ```
+pub trait Collection {
+    /// The type of the items, or of the keys of a map
+    type Item;
+
+    /// The items of the collection, in iteration order
+    fn items(&self) -> impl Iterator<Item = &Self::Item>;
+}
+
+macro_rules! impl_collection {
+    ($($item:ident in <$($g:ident),*> $t:ty => $iter:ident),* $(,)?) => {
+        $(
+            impl<$($g),*> Collection for $t {
+                type Item = $item;
+
+                fn items(&self) -> impl Iterator<Item = &Self::Item> {
+                    self.$iter()
+                }
+            }
+        )*
+    };
+}
+
+impl_collection!(
+    T in <T> [T] => iter,
+    T in <T> Vec<T> => iter,
+    T in <T> VecDeque<T> => iter,
+    T in <T> LinkedList<T> => iter,
+    T in <T> BinaryHeap<T> => iter,
+    T in <T> BTreeSet<T> => iter,
+    T in <T, S> HashSet<T, S> => iter,
+    T in <T> Option<T> => iter,
+    K in <K, V> BTreeMap<K, V> => keys,
+    K in <K, V, S> HashMap<K, V, S> => keys,
+);
+
+#[cfg(feature = "use-indexmap")]
+impl_collection!(
+    T in <T, S> indexmap::IndexSet<T, S> => iter,
+    K in <K, V, S> indexmap::IndexMap<K, V, S> => keys,
+);
+
+impl<T, const N: usize> Collection for [T; N] {
+    type Item = T;
+
+    fn items(&self) -> impl Iterator<Item = &Self::Item> {
+        self.iter()
+    }
+}
+
+impl<T> Collection for Box<T>
+where
+    T: Collection + ?Sized,
+{
+    type Item = T::Item;
+
+    fn items(&self) -> impl Iterator<Item = &Self::Item> {
+        self.as_ref().items()
+    }
+}
+
+impl<T> Collection for &T
+where
+    T: Collection + ?Sized,
+{
+    type Item = T::Item;
+
+    fn items(&self) -> impl Iterator<Item = &Self::Item> {
+        (**self).items()
+    }
+}
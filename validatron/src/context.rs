@@ -0,0 +1,234 @@
+use crate::{Error, Result};
+
+/// Validation that depends on runtime data, such as the set of known identifiers or the
+/// current time, supplied as a context of type `C`.
+///
+/// Deriving [`Validate`](crate::Validate) on a type annotated with
+/// `#[validatron(context = "MyCtx")]` implements `ValidateWith<MyCtx>` instead, the context
+/// is available to `context_function` validators and to expressions as `ctx`, and is passed
+/// on to every nested field.
+///
+/// Types that derive plain [`Validate`](crate::Validate) implement `ValidateWith<C>` for any
+/// context by ignoring it, so that they, and collections of them, can be nested within types
+/// that need a context. Types implementing `Validate` by hand can do the same:
+///
+/// ```
+/// use validatron::{Error, Result, Validate, ValidateWith};
+///
+/// struct Callsign(String);
+///
+/// impl Validate for Callsign {
+///     fn validate(&self) -> Result<()> {
+///         if self.0.is_empty() {
+///             Err(Error::new("empty callsign"))
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// impl<C: ?Sized> ValidateWith<C> for Callsign {
+///     fn validate_with(&self, _: &C) -> Result<()> {
+///         self.validate()
+///     }
+/// }
+/// ```
+///
+/// Nested fields are validated with `ValidateWith`, so generic fields must be bound by
+/// `ValidateWith<MyCtx>` rather than `Validate`.
+///
+/// ```
+/// use validatron::{Error, Result, Validate, ValidateWith};
+///
+/// struct Stations {
+///     known: Vec<u32>,
+/// }
+///
+/// fn is_known(id: &u32, ctx: &Stations) -> Result<()> {
+///     if ctx.known.contains(id) {
+///         Ok(())
+///     } else {
+///         Err(Error::new(format!("unknown station {}", id)))
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// #[validatron(context = "Stations")]
+/// struct Contact {
+///     #[validatron(context_function = "is_known")]
+///     station: u32,
+///     #[validatron(max = "ctx.known.len()")]
+///     passes: usize,
+/// }
+///
+/// let ctx = Stations { known: vec![1, 2] };
+///
+/// assert!(Contact { station: 1, passes: 2 }.validate_with(&ctx).is_ok());
+/// assert!(Contact { station: 3, passes: 2 }.validate_with(&ctx).is_err());
+/// assert!(vec![Contact { station: 1, passes: 3 }].validate_with(&ctx).is_err());
+/// ```
+pub trait ValidateWith<C: ?Sized> {
+    /// Validate the implemented type exhaustively with the given context, returning all
+    /// errors.
+    fn validate_with(&self, ctx: &C) -> Result<()>;
+}
+
+fn validate_seq_with<'a, I, T, C>(sequence: I, ctx: &C) -> Result<()>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: ValidateWith<C> + 'a,
+    C: ?Sized,
+{
+    let mut eb = Error::build();
+
+    for (i, x) in sequence.into_iter().enumerate() {
+        eb.try_at_index(i, x.validate_with(ctx));
+    }
+
+    eb.build()
+}
+
+fn validate_map_with<'a, I, K, V, C>(map: I, ctx: &C) -> Result<()>
+where
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    K: std::fmt::Display + 'a,
+    V: ValidateWith<C> + 'a,
+    C: ?Sized,
+{
+    let mut eb = Error::build();
+
+    for (k, v) in map {
+        eb.try_at_named(k.to_string(), v.validate_with(ctx));
+    }
+
+    eb.build()
+}
+
+impl<T, C> ValidateWith<C> for Vec<T>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+impl<T, C> ValidateWith<C> for std::collections::VecDeque<T>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+impl<T, C> ValidateWith<C> for std::collections::LinkedList<T>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+impl<K, V, S, C> ValidateWith<C> for std::collections::HashMap<K, V, S>
+where
+    K: std::fmt::Display,
+    V: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_map_with(self, ctx)
+    }
+}
+
+impl<K, V, C> ValidateWith<C> for std::collections::BTreeMap<K, V>
+where
+    K: std::fmt::Display,
+    V: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_map_with(self, ctx)
+    }
+}
+
+impl<T, S, C> ValidateWith<C> for std::collections::HashSet<T, S>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+impl<T, C> ValidateWith<C> for std::collections::BTreeSet<T>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+impl<T, C> ValidateWith<C> for std::collections::BinaryHeap<T>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+#[cfg(feature = "use-indexmap")]
+impl<K, V, C> ValidateWith<C> for indexmap::IndexMap<K, V>
+where
+    K: std::fmt::Display,
+    V: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_map_with(self, ctx)
+    }
+}
+
+#[cfg(feature = "use-indexmap")]
+impl<T, S, C> ValidateWith<C> for indexmap::IndexSet<T, S>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+impl<T, C> ValidateWith<C> for Option<T>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        validate_seq_with(self, ctx)
+    }
+}
+
+impl<T, E, C> ValidateWith<C> for std::result::Result<T, E>
+where
+    T: ValidateWith<C>,
+    C: ?Sized,
+{
+    fn validate_with(&self, ctx: &C) -> Result<()> {
+        if let Ok(value) = self {
+            value.validate_with(ctx)
+        } else {
+            Err(Error::new("value is an Error"))
+        }
+    }
+}
@@ -1,17 +1,24 @@
-use std::{borrow::Cow, collections::BTreeMap};
-use thiserror::Error;
+use std::{borrow::Cow, collections::BTreeMap, fmt::Display};
 
+use crate::catalog::{render_template, write_template};
 use crate::Result;
 
 #[cfg(feature = "use-serde")]
-use serde::Serialize;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// The location within a data structure in which a validation error could
 /// occur. Similar to serde we only support json style data structures with
 /// either numerically indexed or keyed locations.
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd)]
-#[cfg_attr(feature = "use-serde", derive(Serialize), serde(untagged))]
+///
+/// When serialized, indexes are written as integers and names as strings. Formats
+/// such as JSON only support string keys, so a name that would read back as an
+/// index (e.g. `"0"`) or that starts with a `\` is escaped with a leading `\`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum Location {
+    /// Where validation records that it stopped early, see
+    /// [`ValidationOptions`](crate::ValidationOptions). This is not a location within the
+    /// data structure, so it never collides with a field and is left out of paths.
+    Truncated,
     // todo: can this be <'a>?
     /// A keyed location, this could be a struct field or a map key
     Named(Cow<'static, str>),
@@ -19,23 +26,514 @@ pub enum Location {
     Index(usize),
 }
 
-// todo: use a none-str type as the reason type?
+#[cfg(feature = "use-serde")]
+impl Location {
+    // names which `Location::deserialize` would otherwise read back as an index
+    fn is_ambiguous_name(name: &str) -> bool {
+        name.starts_with('\\') || Self::parse_index(name).is_some()
+    }
+
+    // escaped names continue with an index or another `\`, so no name is written as this
+    const TRUNCATED: &'static str = "\\truncated";
+
+    fn parse_index(s: &str) -> Option<usize> {
+        s.parse()
+            .ok()
+            .filter(|i: &usize| s == i.to_string().as_str())
+    }
+}
+
+#[cfg(feature = "use-serde")]
+impl Serialize for Location {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Location::Named(name) if Location::is_ambiguous_name(name) => {
+                serializer.collect_str(&format_args!("\\{}", name))
+            }
+            Location::Named(name) => serializer.serialize_str(name),
+            Location::Index(i) => serializer.serialize_u64(*i as u64),
+            Location::Truncated => serializer.serialize_str(Location::TRUNCATED),
+        }
+    }
+}
+
+#[cfg(feature = "use-serde")]
+impl<'de> Deserialize<'de> for Location {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LocationVisitor;
+
+        impl de::Visitor<'_> for LocationVisitor {
+            type Value = Location;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a string or an unsigned integer")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> std::result::Result<Location, E> {
+                usize::try_from(v)
+                    .map(Location::Index)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> std::result::Result<Location, E> {
+                usize::try_from(v)
+                    .map(Location::Index)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> std::result::Result<Location, E> {
+                if v == Location::TRUNCATED {
+                    return Ok(Location::Truncated);
+                }
+
+                Ok(match v.strip_prefix('\\') {
+                    Some(name) => Location::Named(name.to_string().into()),
+                    None => match Location::parse_index(v) {
+                        Some(i) => Location::Index(i),
+                        None => Location::Named(v.to_string().into()),
+                    },
+                })
+            }
+        }
+
+        deserializer.deserialize_any(LocationVisitor)
+    }
+}
+
+/// A parameter of a validation failure, such as the minimum a value was compared to.
+///
+/// Parameters are typed so that they serialize as numbers, booleans or strings.
+/// Integers compare by value, so `Param::Int(1) == Param::UInt(1)`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "use-serde", derive(Serialize), serde(untagged))]
+pub enum Param {
+    /// A boolean parameter
+    Bool(bool),
+    /// A signed integer parameter
+    Int(i64),
+    /// An unsigned integer parameter
+    UInt(u64),
+    /// A floating point parameter
+    Float(f64),
+    /// A textual parameter
+    Str(Cow<'static, str>),
+}
+
+impl Param {
+    /// Construct a parameter from the [`Display`] representation of a value.
+    ///
+    /// Validators are generic over any displayable type, so the type of the value, rather
+    /// than its rendered text, decides the kind of parameter. The primitive integers, floats
+    /// and `bool`, and references to them, are recorded as numbers and booleans, any other
+    /// value as text. Non-finite floats are recorded as text, as they have no number
+    /// representation in formats such as JSON.
+    ///
+    /// ```
+    /// # use validatron::Param;
+    /// assert_eq!(Param::display(&42), Param::UInt(42));
+    /// assert_eq!(Param::display(&-1.5), Param::Float(-1.5));
+    /// assert_eq!(Param::display(&&true), Param::Bool(true));
+    /// assert_eq!(Param::display(&"hello"), Param::Str("hello".into()));
+    /// assert!(matches!(Param::display(&"42"), Param::Str(_)));
+    /// assert!(matches!(Param::display(&String::from("true")), Param::Str(_)));
+    ///
+    /// // text displayed by a wrapper is kept as text
+    /// struct Id(String);
+    ///
+    /// impl std::fmt::Display for Id {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         write!(f, "{}", self.0)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Param::display(&Id("42".into())), Param::Str("42".into()));
+    /// ```
+    pub fn display<T>(value: &T) -> Self
+    where
+        T: Display + ?Sized,
+    {
+        let kind = primitive_kind(std::any::type_name::<T>());
+
+        if kind == Primitive::Other {
+            return Param::Str(value.to_string().into());
+        }
+
+        // primitives are rendered without allocating, and always parse back to their value
+        let mut buffer = InlineBuffer::default();
+
+        let s = match std::fmt::write(&mut buffer, format_args!("{}", value)) {
+            Ok(()) => buffer.as_str(),
+            Err(_) => return Param::Str(value.to_string().into()),
+        };
+
+        let param = match kind {
+            Primitive::Bool => s.parse().ok().map(Param::Bool),
+            Primitive::Integer => s
+                .parse::<u64>()
+                .map(Param::UInt)
+                .or_else(|_| s.parse::<i64>().map(Param::Int))
+                .ok(),
+            Primitive::Float => s
+                .parse::<f64>()
+                .ok()
+                .filter(|x| x.is_finite())
+                .map(Param::Float),
+            Primitive::Other => None,
+        };
+
+        // such as a u128 out of the range of a u64
+        param.unwrap_or_else(|| Param::Str(s.to_string().into()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Primitive {
+    Bool,
+    Integer,
+    Float,
+    Other,
+}
+
+// the kind of a primitive type by name, seeing through references. The primitives are named
+// without a path, so no other type shares their names.
+fn primitive_kind(mut name: &str) -> Primitive {
+    while let Some(rest) = name.strip_prefix('&') {
+        name = rest.strip_prefix("mut ").unwrap_or(rest);
+    }
+
+    match name {
+        "bool" => Primitive::Bool,
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => Primitive::Integer,
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => Primitive::Integer,
+        "f32" | "f64" => Primitive::Float,
+        _ => Primitive::Other,
+    }
+}
+
+// a fixed size buffer for rendering short values, writes fail once it is full
+struct InlineBuffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Default for InlineBuffer {
+    fn default() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+}
+
+impl InlineBuffer {
+    fn as_str(&self) -> &str {
+        // only whole strs are ever written
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl std::fmt::Write for InlineBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        let dest = self.bytes.get_mut(self.len..end).ok_or(std::fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "use-serde")]
+impl<'de> Deserialize<'de> for Param {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ParamVisitor;
+
+        impl de::Visitor<'_> for ParamVisitor {
+            type Value = Param;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a boolean, number or string")
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> std::result::Result<Param, E> {
+                Ok(Param::Bool(v))
+            }
+
+            // non-negative integers are unsigned, matching `Param::display`
+            fn visit_i64<E: de::Error>(self, v: i64) -> std::result::Result<Param, E> {
+                Ok(u64::try_from(v).map_or(Param::Int(v), Param::UInt))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> std::result::Result<Param, E> {
+                Ok(Param::UInt(v))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> std::result::Result<Param, E> {
+                Ok(Param::Float(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> std::result::Result<Param, E> {
+                Ok(Param::Str(v.to_string().into()))
+            }
+        }
+
+        deserializer.deserialize_any(ParamVisitor)
+    }
+}
+
+impl PartialEq for Param {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Param::Bool(a), Param::Bool(b)) => a == b,
+            (Param::Int(a), Param::Int(b)) => a == b,
+            (Param::UInt(a), Param::UInt(b)) => a == b,
+            (Param::Int(a), Param::UInt(b)) | (Param::UInt(b), Param::Int(a)) => {
+                u64::try_from(*a) == Ok(*b)
+            }
+            (Param::Float(a), Param::Float(b)) => a == b,
+            (Param::Str(a), Param::Str(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Param::Bool(x) => x.fmt(f),
+            Param::Int(x) => x.fmt(f),
+            Param::UInt(x) => x.fmt(f),
+            Param::Float(x) => x.fmt(f),
+            Param::Str(x) => x.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_param_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(
+            impl From<$t> for Param {
+                fn from(value: $t) -> Self {
+                    Param::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_param_from!(Bool: bool);
+impl_param_from!(Int: i8, i16, i32, i64);
+impl_param_from!(UInt: u8, u16, u32, u64);
+impl_param_from!(Float: f32, f64);
+impl_param_from!(Str: &'static str, String, Cow<'static, str>);
+
+/// A single validation failure.
+///
+/// Each reason carries a stable, machine readable `code` (such as `"min"` or
+/// `"required"`), the parameters of the check that failed, and a human readable message.
+///
+/// The inbuilt validators only record the code and parameters, their messages are
+/// rendered from the [default templates](crate::catalog::DefaultCatalog) when displayed
+/// or serialized.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "use-serde", derive(Deserialize))]
+pub struct Reason {
+    code: Cow<'static, str>,
+    message: Option<Cow<'static, str>>,
+    params: BTreeMap<Cow<'static, str>, Param>,
+    // the message was supplied with `with_message`, so catalogs leave it alone, serialized as
+    // `custom: true` so that this survives a round trip
+    #[cfg_attr(feature = "use-serde", serde(default, rename = "custom"))]
+    custom_message: bool,
+}
+
+impl Reason {
+    /// The code given to reasons constructed from a bare message
+    pub const CUSTOM: &'static str = "custom";
+
+    /// Construct a new reason with the given code and message
+    ///
+    /// ```
+    /// # use validatron::{Error, Reason};
+    /// let e: Error = Reason::new("is_identifier", "is not a valid identifier")
+    ///     .with_param("value", "a b")
+    ///     .into();
+    /// ```
+    pub fn new(code: impl Into<Cow<'static, str>>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            code: code.into(),
+            message: Some(message.into()),
+            params: BTreeMap::new(),
+            custom_message: false,
+        }
+    }
+
+    /// Construct a new reason whose message is rendered from the default template of its
+    /// code, see [`DefaultCatalog`](crate::catalog::DefaultCatalog)
+    ///
+    /// ```
+    /// # use validatron::Reason;
+    /// let reason = Reason::from_code("min")
+    ///     .with_param("min", 42)
+    ///     .with_param("value", 36);
+    ///
+    /// assert_eq!(reason.message(), "'36' must be greater than or equal to '42'");
+    /// ```
+    pub fn from_code(code: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            code: code.into(),
+            message: None,
+            params: BTreeMap::new(),
+            custom_message: false,
+        }
+    }
+
+    /// Attach a named parameter to the reason
+    pub fn with_param(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Param>,
+    ) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Replace the message of the reason, retaining its code and parameters.
+    ///
+    /// The replacement is kept when the error is [localized](Error::localize).
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.set_message(message);
+        self.custom_message = true;
+        self
+    }
+
+    pub(crate) fn set_message(&mut self, message: impl Into<Cow<'static, str>>) {
+        self.message = Some(message.into());
+    }
+
+    /// Whether the message was replaced with [`with_message`](Reason::with_message)
+    pub fn has_custom_message(&self) -> bool {
+        self.custom_message
+    }
+
+    /// The machine readable code identifying the check that failed
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// The human readable message, rendered from the default template of the code unless
+    /// one was given. Codes without a template are rendered as the code itself.
+    pub fn message(&self) -> Cow<'_, str> {
+        match &self.message {
+            Some(message) => Cow::Borrowed(message),
+            None => match crate::catalog::default_template(&self.code) {
+                Some(template) => Cow::Owned(render_template(template, &self.params)),
+                None => Cow::Borrowed(&self.code),
+            },
+        }
+    }
+
+    /// The parameters of the check that failed
+    pub fn params(&self) -> &BTreeMap<Cow<'static, str>, Param> {
+        &self.params
+    }
+}
+
+// reasons are equal when they would be displayed and serialized the same, whether or not
+// their messages have been rendered
+impl PartialEq for Reason {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code && self.params == other.params && self.message() == other.message()
+    }
+}
+
+/// Displays the message of the reason, rendering it without allocating
+impl Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.message {
+            Some(message) => f.write_str(message),
+            None => match crate::catalog::default_template(&self.code) {
+                Some(template) => write_template(f, template, &self.params),
+                None => f.write_str(&self.code),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "use-serde")]
+impl Serialize for Reason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let len = if self.custom_message { 4 } else { 3 };
+
+        let mut state = serializer.serialize_struct("Reason", len)?;
+        state.serialize_field("code", &self.code)?;
+        state.serialize_field("message", &format_args!("{}", self))?;
+        state.serialize_field("params", &self.params)?;
+
+        if self.custom_message {
+            state.serialize_field("custom", &true)?;
+        } else {
+            state.skip_field("custom")?;
+        }
+
+        state.end()
+    }
+}
+
+impl From<Cow<'static, str>> for Reason {
+    fn from(message: Cow<'static, str>) -> Self {
+        Self::new(Self::CUSTOM, message)
+    }
+}
+
+impl From<&'static str> for Reason {
+    fn from(message: &'static str) -> Self {
+        Self::new(Self::CUSTOM, message)
+    }
+}
+
+impl From<String> for Reason {
+    fn from(message: String) -> Self {
+        Self::new(Self::CUSTOM, message)
+    }
+}
+
 /// A type that represents all validation issues that arise during the validation
 /// of the given data type.
-#[derive(Error, Debug, PartialEq)]
-#[cfg_attr(feature = "use-serde", derive(Serialize), serde(untagged))]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "use-serde",
+    derive(Serialize, Deserialize),
+    serde(
+        untagged,
+        expecting = "a list of reasons or a map of locations to errors"
+    )
+)]
 pub enum Error {
     /// A flat, unstructured list of failure reasons
-    #[error("{0:#?}")]
-    Unstructured(Vec<Cow<'static, str>>),
+    Unstructured(Vec<Reason>),
 
     /// A structured, potentially nested set of failure reasons
     ///
     /// a vector or a nested map can attribute errors to the correct locations
-    #[error("{0:#?}")]
     Structured(BTreeMap<Location, Error>),
 }
 
+impl std::error::Error for Error {}
+
 impl Error {
     /// Constructs a new unstructured [`enum@Error`] with a single message
     ///
@@ -47,7 +545,38 @@ impl Error {
     where
         S: Into<Cow<'static, str>>,
     {
-        Self::Unstructured(vec![message.into()])
+        Self::Unstructured(vec![Reason::from(message.into())])
+    }
+
+    /// Replace the message of every failure reason in an [`enum@Error`], retaining their
+    /// codes, parameters and locations
+    ///
+    /// ```
+    /// # use validatron::{Error, Reason};
+    /// let e = Error::from(Reason::new("min", "'1' must be greater than or equal to '2'"))
+    ///     .with_message("too small");
+    ///
+    /// assert_eq!(e, Error::from(Reason::new("min", "too small")));
+    /// ```
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.set_messages(message.into());
+        self
+    }
+
+    fn set_messages(&mut self, message: Cow<'static, str>) {
+        match self {
+            Error::Unstructured(reasons) => {
+                for reason in reasons {
+                    reason.set_message(message.clone());
+                    reason.custom_message = true;
+                }
+            }
+            Error::Structured(errors) => {
+                for error in errors.values_mut() {
+                    error.set_messages(message.clone());
+                }
+            }
+        }
     }
 
     /// Merge 2 existing [`enum@Error`] types
@@ -96,42 +625,40 @@ impl Error {
 
     /// create a new [`ErrorBuilder`] instance
     pub fn build() -> ErrorBuilder {
-        ErrorBuilder { errors: None }
+        ErrorBuilder {
+            errors: BTreeMap::new(),
+        }
     }
 }
 
-/// A convenience type for building a structured error type
-pub struct ErrorBuilder {
-    errors: Option<Error>,
+impl From<Reason> for Error {
+    fn from(reason: Reason) -> Self {
+        Error::Unstructured(vec![reason])
+    }
 }
 
-fn build_structured(errs: &mut Option<Error>, loc: Location, error: Error) {
-    let mut structured_errs = errs
-        .take()
-        .map(|e| match e {
-            Error::Unstructured(_) => panic!("should never happen"),
-            Error::Structured(hm) => hm,
-        })
-        .unwrap_or_else(BTreeMap::new);
-
-    use std::collections::btree_map::Entry;
-    match structured_errs.entry(loc) {
-        Entry::Occupied(mut entry) => {
-            entry.get_mut().merge(error);
-        }
-        Entry::Vacant(entry) => {
-            entry.insert(error);
-        }
-    };
-
-    *errs = Some(Error::Structured(structured_errs));
+/// A convenience type for building a structured error type
+pub struct ErrorBuilder {
+    errors: BTreeMap<Location, Error>,
 }
 
 impl ErrorBuilder {
+    fn insert(&mut self, location: Location, error: Error) {
+        use std::collections::btree_map::Entry;
+        match self.errors.entry(location) {
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().merge(error);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(error);
+            }
+        };
+    }
+
     /// does the builder contain any error messages, used to short circuit
     /// various functions if no error has been detected.
     pub fn contains_errors(&self) -> bool {
-        self.errors.is_some()
+        !self.errors.is_empty()
     }
 
     /// Consume the builder and produce a [`Result`]
@@ -144,22 +671,16 @@ impl ErrorBuilder {
     /// assert!(e.is_err());
     /// ```
     pub fn build(&mut self) -> Result<()> {
-        if let Some(e) = self.errors.take() {
-            Err(e)
-        } else {
+        if self.errors.is_empty() {
             Ok(())
+        } else {
+            Err(Error::Structured(std::mem::take(&mut self.errors)))
         }
     }
 
     /// extend the existing builder with an error at the specified location
-    pub fn at_location(
-        &mut self,
-        location: Location,
-        message: impl Into<Cow<'static, str>>,
-    ) -> &mut Self {
-        let e = Error::new(message);
-
-        build_structured(&mut self.errors, location, e);
+    pub fn at_location(&mut self, location: Location, reason: impl Into<Reason>) -> &mut Self {
+        self.insert(location, Error::from(reason.into()));
 
         self
     }
@@ -175,9 +696,9 @@ impl ErrorBuilder {
     pub fn at_named(
         &mut self,
         name: impl Into<Cow<'static, str>>,
-        message: impl Into<Cow<'static, str>>,
+        reason: impl Into<Reason>,
     ) -> &mut Self {
-        self.at_location(Location::Named(name.into()), message)
+        self.at_location(Location::Named(name.into()), reason)
     }
 
     /// extend an existing builder with an error at an indexed location
@@ -188,8 +709,8 @@ impl ErrorBuilder {
     ///     .at_index(1, "value should be even")
     ///     .build();
     /// ```
-    pub fn at_index(&mut self, index: usize, message: impl Into<Cow<'static, str>>) -> &mut Self {
-        self.at_location(Location::Index(index), message)
+    pub fn at_index(&mut self, index: usize, reason: impl Into<Reason>) -> &mut Self {
+        self.at_location(Location::Index(index), reason)
     }
 
     /// extend the existing builder at the specified location if the result is an error
@@ -203,7 +724,7 @@ impl ErrorBuilder {
     /// ```
     pub fn try_at_location(&mut self, location: Location, result: Result<()>) -> &mut Self {
         if let Err(e) = result {
-            build_structured(&mut self.errors, location, e);
+            self.insert(location, e);
         }
 
         self
@@ -226,6 +747,38 @@ impl ErrorBuilder {
         self.try_at_location(Location::Named(name.into()), result)
     }
 
+    /// merge the locations of an error into the builder if the result is an error, as
+    /// is done for `#[serde(flatten)]` fields.
+    ///
+    /// Unstructured errors are reported at an `errors` location, as per [`Error::merge`].
+    ///
+    /// ```
+    /// # use validatron::{Error, Location};
+    /// let inner = Error::build().at_named("a", "broken").build();
+    ///
+    /// let e = Error::build()
+    ///     .try_merge(inner)
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(e, Error::build().at_named("a", "broken").build().unwrap_err());
+    /// ```
+    pub fn try_merge(&mut self, result: Result<()>) -> &mut Self {
+        match result {
+            Ok(()) => {}
+            Err(Error::Structured(errors)) => {
+                for (location, e) in errors {
+                    self.insert(location, e);
+                }
+            }
+            Err(e @ Error::Unstructured(_)) => {
+                self.insert(Location::Named(Cow::from("errors")), e);
+            }
+        }
+
+        self
+    }
+
     /// extend an existing builder with an error at an indexed location if the result is an error
     ///
     /// ```
@@ -325,6 +878,99 @@ mod tests {
         assert!(validate_foo(&value).is_err());
     }
 
+    #[test]
+    fn test_reason_from_message() {
+        let e = Error::new("foo");
+
+        assert_eq!(e, Error::from(Reason::new(Reason::CUSTOM, "foo")));
+    }
+
+    #[test]
+    fn test_lazy_messages() {
+        let lazy = Reason::from_code("max")
+            .with_param("max", 1)
+            .with_param("value", 2);
+        let eager = Reason::new("max", "'2' must be less than or equal to '1'")
+            .with_param("max", 1)
+            .with_param("value", 2);
+
+        assert_eq!(lazy.message(), eager.message());
+        assert_eq!(lazy, eager);
+        assert_ne!(lazy, lazy.clone().with_message("too big"));
+        assert_eq!(Reason::from_code("unknown").message(), "unknown");
+
+        let e = crate::validators::is_equal(&"007", "7").unwrap_err();
+        assert_eq!(e.to_string(), "'007' must equal '7'");
+    }
+
+    #[cfg(feature = "use-serde")]
+    #[test]
+    fn test_reason_serialize() {
+        let e = Error::build()
+            .at_named(
+                "a",
+                Reason::new("min", "too small")
+                    .with_param("min", 42)
+                    .with_param("value", 36),
+            )
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            serde_yaml::to_string(&e).unwrap(),
+            "a:\n- code: min\n  message: too small\n  params:\n    min: 42\n    value: 36\n"
+        );
+
+        let e = Error::from(
+            Reason::from_code("min")
+                .with_param("min", 42)
+                .with_param("value", 36),
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&e).unwrap(),
+            "- code: min\n  message: '''36'' must be greater than or equal to ''42'''\n  params:\n    min: 42\n    value: 36\n"
+        );
+    }
+
+    #[cfg(feature = "use-serde")]
+    #[test]
+    fn test_param_display_keeps_text() {
+        let e = crate::validators::is_equal(&String::from("42"), "7").unwrap_err();
+
+        assert_eq!(
+            serde_yaml::to_string(&e).unwrap(),
+            "- code: equal\n  message: '''42'' must equal ''7'''\n  params:\n    equal: '7'\n    value: '42'\n"
+        );
+
+        let e = crate::validators::is_equal(&42, 7).unwrap_err();
+        assert!(serde_yaml::to_string(&e)
+            .unwrap()
+            .contains("    equal: 7\n"));
+    }
+
+    #[test]
+    fn test_param_kind_follows_type() {
+        struct Id(&'static str);
+
+        impl Display for Id {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        assert_eq!(Param::display(&Id("42")), Param::Str("42".into()));
+        assert_eq!(Param::display(&Id("true")), Param::Str("true".into()));
+        assert_eq!(Param::display(&&mut 7u8), Param::UInt(7));
+        assert_eq!(Param::display(&-7i128), Param::Int(-7));
+        assert_eq!(Param::display(&2.0f64), Param::Float(2.0));
+        assert_eq!(Param::display(&f64::NAN), Param::Str("NaN".into()));
+        assert_eq!(
+            Param::display(&u128::MAX),
+            Param::Str(u128::MAX.to_string().into())
+        );
+    }
+
     #[test]
     fn test_errors() {
         let _e = Error::new("foo");
@@ -340,7 +986,8 @@ mod tests {
 
         match a {
             Error::Unstructured(x) => {
-                assert_eq!(x, vec!["a", "b"]);
+                let messages: Vec<_> = x.iter().map(Reason::message).collect();
+                assert_eq!(messages, vec!["a", "b"]);
             }
             Error::Structured(_) => panic!("should not happen"),
         }
@@ -0,0 +1,229 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+/// Types that know their length without iterating over their contents, measured by the
+/// length validators such as [`is_min_length`](crate::validators::is_min_length).
+///
+/// Text is measured in bytes, see [`Unit`](crate::validators::Unit) for counting other
+/// units. The length validators take any iterable, [`Len`] passes them a length read
+/// through this trait.
+///
+/// ```
+/// # use validatron::HasLength;
+/// let x = std::collections::LinkedList::from([1, 2, 3]);
+/// assert_eq!(x.length(), 3);
+/// assert_eq!("Zürich".length(), 7);
+/// ```
+pub trait HasLength {
+    /// The number of items in the collection, or bytes in the text
+    fn length(&self) -> usize;
+}
+
+macro_rules! impl_has_length {
+    ($(<$($g:ident),*> $t:ty),* $(,)?) => {
+        $(
+            impl<$($g),*> HasLength for $t {
+                fn length(&self) -> usize {
+                    self.len()
+                }
+            }
+        )*
+    };
+}
+
+impl_has_length!(
+    <T> [T],
+    <T> Vec<T>,
+    <T> VecDeque<T>,
+    <T> LinkedList<T>,
+    <T> BinaryHeap<T>,
+    <T> BTreeSet<T>,
+    <K, V> BTreeMap<K, V>,
+    <T, S> HashSet<T, S>,
+    <K, V, S> HashMap<K, V, S>,
+    <> str,
+    <> String,
+);
+
+#[cfg(feature = "use-indexmap")]
+impl_has_length!(
+    <T, S> indexmap::IndexSet<T, S>,
+    <K, V, S> indexmap::IndexMap<K, V, S>,
+);
+
+impl<T, const N: usize> HasLength for [T; N] {
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<B> HasLength for Cow<'_, B>
+where
+    B: HasLength + ToOwned + ?Sized,
+{
+    fn length(&self) -> usize {
+        self.as_ref().length()
+    }
+}
+
+impl<T> HasLength for Box<T>
+where
+    T: HasLength + ?Sized,
+{
+    fn length(&self) -> usize {
+        self.as_ref().length()
+    }
+}
+
+impl<T> HasLength for &T
+where
+    T: HasLength + ?Sized,
+{
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// The number of items of an iterable, read from the size hint of its iterator when that
+/// is exact, as it is for the std collections, and counted otherwise.
+pub(crate) fn count<C>(iterable: C) -> usize
+where
+    C: IntoIterator,
+{
+    let iter = iterable.into_iter();
+    exact_size(&iter).unwrap_or_else(|| iter.count())
+}
+
+pub(crate) fn exact_size<I>(iter: &I) -> Option<usize>
+where
+    I: Iterator,
+{
+    match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Some(lower),
+        _ => None,
+    }
+}
+
+/// A length measured up front, passed to the length validators as an iterable of that many
+/// items.
+///
+/// ```
+/// # use validatron::length::Len;
+/// # use validatron::validators::is_min_length;
+/// assert!(is_min_length(Len::of("Zürich"), 7).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Len(pub usize);
+
+impl Len {
+    /// The length of a value implementing [`HasLength`]
+    pub fn of<T>(value: &T) -> Self
+    where
+        T: HasLength + ?Sized,
+    {
+        Len(value.length())
+    }
+}
+
+impl HasLength for Len {
+    fn length(&self) -> usize {
+        self.0
+    }
+}
+
+impl IntoIterator for Len {
+    type Item = usize;
+    type IntoIter = std::ops::Range<usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        0..self.0
+    }
+}
+
+// The derive macro measures fields with `(&Measure(&field)).measure()`, which resolves to
+// `ByLength` when the field implements `HasLength`, and to counting the items of any other
+// iterable through `ByCounting` as method lookup only tries the reference after the value.
+
+#[doc(hidden)]
+pub struct Measure<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ByLength {
+    fn measure(&self) -> Len;
+}
+
+impl<T> ByLength for Measure<'_, T>
+where
+    T: HasLength + ?Sized,
+{
+    fn measure(&self) -> Len {
+        Len::of(self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait ByCounting {
+    fn measure(&self) -> Len;
+}
+
+impl<'a, T> ByCounting for &Measure<'a, T>
+where
+    T: ?Sized,
+    &'a T: IntoIterator,
+{
+    fn measure(&self) -> Len {
+        Len(count(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Countdown(usize);
+
+    impl IntoIterator for &Countdown {
+        type Item = usize;
+        type IntoIter = std::iter::Rev<std::ops::Range<usize>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            (0..self.0).rev()
+        }
+    }
+
+    // measured as the derive macro does, the borrow selects the fallback
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn measure_prefers_has_length() {
+        let x = vec![1, 2, 3];
+        assert_eq!((&Measure(&x)).measure(), Len(3));
+
+        let x = Countdown(4);
+        assert_eq!((&Measure(&x)).measure(), Len(4));
+    }
+
+    #[test]
+    fn lengths() {
+        assert_eq!([0u8; 4].length(), 4);
+        assert_eq!(Cow::Borrowed("é").length(), 2);
+        assert_eq!(Box::<[u8]>::from(vec![1, 2]).length(), 2);
+        assert_eq!(
+            [(1, 2), (3, 4)]
+                .into_iter()
+                .collect::<HashMap<_, _>>()
+                .length(),
+            2
+        );
+    }
+
+    #[test]
+    fn count_iterables() {
+        let x = LinkedList::from([1, 2, 3]);
+        assert_eq!(exact_size(&x.iter()), Some(3));
+        assert_eq!(count(&x), 3);
+
+        let odd = x.iter().filter(|x| **x % 2 == 1);
+        assert_eq!(exact_size(&odd), None);
+        assert_eq!(count(odd), 2);
+    }
+}
@@ -25,8 +25,52 @@ pub mod error;
 /// pre-rolled validators for data structures
 pub mod validators;
 
+/// Measuring the length of collections and text
+pub mod length;
+
+/// Numeric properties checked by the validators
+pub mod number;
+
+/// Searching and ordering the items of collections
+pub mod collection;
+
+/// Predicates backing the validators, used by [`Validate::is_valid`], which allocate only for
+/// `unique` on collections of more than a few items
+pub mod checks;
+
+mod formats;
+
+/// Translation of validation failures into other languages
+pub mod catalog;
+
+/// Paths to the locations of validation failures
+pub mod path;
+
+/// Configurable human readable rendering of validation failures
+pub mod render;
+
+/// Validation with a runtime context
+pub mod context;
+
+/// Validation that awaits I/O
+pub mod asynchronous;
+
+/// Limiting how many errors validation collects
+pub mod options;
+
 // re-export derive macro
-pub use error::{Error, Location};
+pub use asynchronous::AsyncValidate;
+pub use collection::Collection;
+pub use context::ValidateWith;
+pub use error::{Error, Location, Param, Reason};
+pub use length::HasLength;
+pub use number::Number;
+pub use options::{Budget, ValidationOptions};
+pub use path::Path;
+
+/// The regular expressions matched by the `pattern` validator
+#[cfg(feature = "use-regex")]
+pub use regex;
 
 /// A derive macro for validating data structures
 pub use validatron_derive::Validate;
@@ -41,17 +85,70 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub trait Validate {
     /// Validate the implemented type exhaustively, returning all errors.
     fn validate(&self) -> Result<()>;
+
+    /// Validate the implemented type, stopping once the [`Budget`] is exhausted.
+    ///
+    /// The default implementation validates exhaustively and charges the budget with every
+    /// error found. Implementors containing other data structures should override it to
+    /// pass the budget on, and to skip the remaining checks once [`Budget::stop`] returns
+    /// true.
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        budget.charge(self.validate())
+    }
+
+    /// Whether the implemented type is valid, without collecting any errors.
+    ///
+    /// This stops at the first failure. Derived implementations and those of the std
+    /// collections do not allocate, though `function` validators may, as does `unique` on
+    /// collections of more than a few items.
+    fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Validate the implemented type within the limits of the given options, see
+    /// [`ValidationOptions`].
+    ///
+    /// If validation stopped early the returned [`Error`] records it, see
+    /// [`Error::is_truncated`].
+    fn validate_with_options(&self, options: ValidationOptions) -> Result<()> {
+        let mut budget = Budget::new(options);
+        let result = self.validate_within(&mut budget);
+        budget.finish(result)
+    }
 }
 
-fn validate_seq<'a, I, T: 'a>(sequence: I) -> Result<()>
+fn validate_seq<'a, I, T>(sequence: I, budget: &mut Budget) -> Result<()>
 where
     I: IntoIterator<Item = &'a T>,
-    T: Validate,
+    T: Validate + 'a,
 {
     let mut eb = Error::build();
 
     for (i, x) in sequence.into_iter().enumerate() {
-        eb.try_at_index(i, x.validate());
+        if budget.stop() {
+            break;
+        }
+
+        eb.try_at_index(i, x.validate_within(budget));
+    }
+
+    eb.build()
+}
+
+fn validate_map<'a, I, K, V>(map: I, budget: &mut Budget) -> Result<()>
+where
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    K: std::fmt::Display + 'a,
+    V: Validate + 'a,
+{
+    let mut eb = Error::build();
+
+    for (k, v) in map {
+        if budget.stop() {
+            break;
+        }
+
+        eb.try_at_named(k.to_string(), v.validate_within(budget));
     }
 
     eb.build()
@@ -62,7 +159,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -71,7 +176,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -80,7 +193,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -90,13 +211,15 @@ where
     V: Validate,
 {
     fn validate(&self) -> Result<()> {
-        let mut eb = Error::build();
+        validate_map(self, &mut Budget::unlimited())
+    }
 
-        for (k, v) in self {
-            eb.try_at_named(k.to_string(), v.validate());
-        }
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_map(self, budget)
+    }
 
-        eb.build()
+    fn is_valid(&self) -> bool {
+        self.values().all(V::is_valid)
     }
 }
 
@@ -106,13 +229,15 @@ where
     V: Validate,
 {
     fn validate(&self) -> Result<()> {
-        let mut eb = Error::build();
+        validate_map(self, &mut Budget::unlimited())
+    }
 
-        for (k, v) in self {
-            eb.try_at_named(k.to_string(), v.validate());
-        }
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_map(self, budget)
+    }
 
-        eb.build()
+    fn is_valid(&self) -> bool {
+        self.values().all(V::is_valid)
     }
 }
 
@@ -121,7 +246,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -130,7 +263,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -139,7 +280,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -150,13 +299,15 @@ where
     V: Validate,
 {
     fn validate(&self) -> Result<()> {
-        let mut eb = Error::build();
+        validate_map(self, &mut Budget::unlimited())
+    }
 
-        for (k, v) in self {
-            eb.try_at_named(k.to_string(), v.validate());
-        }
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_map(self, budget)
+    }
 
-        eb.build()
+    fn is_valid(&self) -> bool {
+        self.values().all(V::is_valid)
     }
 }
 
@@ -166,7 +317,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -175,7 +334,15 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
-        validate_seq(self)
+        validate_seq(self, &mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
+        validate_seq(self, budget)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iter().all(T::is_valid)
     }
 }
 
@@ -184,10 +351,18 @@ where
     T: Validate,
 {
     fn validate(&self) -> Result<()> {
+        self.validate_within(&mut Budget::unlimited())
+    }
+
+    fn validate_within(&self, budget: &mut Budget) -> Result<()> {
         if let Ok(value) = self {
-            value.validate()
+            value.validate_within(budget)
         } else {
-            Err(Error::new("value is an Error"))
+            budget.charge(Err(Error::new("value is an Error")))
         }
     }
+
+    fn is_valid(&self) -> bool {
+        self.as_ref().is_ok_and(T::is_valid)
+    }
 }
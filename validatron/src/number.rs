@@ -0,0 +1,134 @@
+use std::fmt::{self, Display, Write};
+
+/// The primitive integers and floats, checked by the numeric validators such as
+/// [`multiple_of`](crate::validators::multiple_of).
+pub trait Number: Copy + Display {
+    /// Whether the number is neither infinite nor NaN, which integers always are
+    fn is_finite(self) -> bool;
+
+    /// Whether the number is a multiple of a factor.
+    ///
+    /// Floats are compared within a few units of precision of their quotient, so that
+    /// `0.3` is a multiple of `0.1`. Only zero is a multiple of zero.
+    fn is_multiple_of(self, factor: Self) -> bool;
+
+    /// The number of digits after the decimal point in the shortest representation of the
+    /// number, as displayed
+    fn decimal_places(self) -> usize;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn is_finite(self) -> bool {
+                    true
+                }
+
+                fn is_multiple_of(self, factor: Self) -> bool {
+                    // only overflows for MIN % -1, which is a multiple
+                    factor == 0 && self == 0
+                        || factor != 0 && self.checked_rem(factor).map_or(true, |x| x == 0)
+                }
+
+                fn decimal_places(self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+
+                fn is_multiple_of(self, factor: Self) -> bool {
+                    if factor == 0.0 {
+                        return self == 0.0;
+                    }
+
+                    let quotient = self / factor;
+                    let tolerance = 4.0 * <$t>::EPSILON * quotient.abs().max(1.0);
+
+                    (quotient - quotient.round()).abs() <= tolerance
+                }
+
+                fn decimal_places(self) -> usize {
+                    let mut places = DecimalPlaces::default();
+
+                    // counting cannot fail
+                    let _ = write!(places, "{}", self);
+
+                    places.count
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+// counts the digits written after a decimal point, without buffering them
+#[derive(Default)]
+struct DecimalPlaces {
+    point: bool,
+    count: usize,
+}
+
+impl Write for DecimalPlaces {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.point {
+                self.count += 1;
+            } else {
+                self.point = c == '.';
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiples() {
+        // called through the trait, as the integers also have an inherent `is_multiple_of`
+        assert!(Number::is_multiple_of(15u32, 5));
+        assert!(!Number::is_multiple_of(16u32, 5));
+        assert!(Number::is_multiple_of(-15i64, 5));
+        assert!(Number::is_multiple_of(i64::MIN, -1));
+        assert!(Number::is_multiple_of(0u8, 0));
+        assert!(!Number::is_multiple_of(1u8, 0));
+
+        assert!(Number::is_multiple_of(0.3f64, 0.1));
+        assert!(Number::is_multiple_of(1.2f64, 0.1));
+        assert!(Number::is_multiple_of(359.5f64, 0.5));
+        assert!(Number::is_multiple_of(1e12f64, 0.25));
+        assert!(Number::is_multiple_of(0.75f32, 0.25));
+        assert!(!Number::is_multiple_of(0.31f64, 0.1));
+        assert!(!Number::is_multiple_of(0.3f32, 0.25));
+        assert!(!Number::is_multiple_of(f64::NAN, 0.1));
+        assert!(!Number::is_multiple_of(f64::INFINITY, 0.1));
+    }
+
+    #[test]
+    fn decimal_places() {
+        assert_eq!(42u64.decimal_places(), 0);
+        assert_eq!(42.0f64.decimal_places(), 0);
+        assert_eq!(0.25f64.decimal_places(), 2);
+        assert_eq!((-1.125f32).decimal_places(), 3);
+        assert_eq!((0.1f64 + 0.2).decimal_places(), 17);
+        assert_eq!(1e-10f64.decimal_places(), 10);
+        assert_eq!(1e300f64.decimal_places(), 0);
+    }
+}
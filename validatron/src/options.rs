@@ -0,0 +1,234 @@
+use crate::{Error, Location, Reason, Result};
+
+/// Options limiting how much work validation does, see
+/// [`Validate::validate_with_options`](crate::Validate::validate_with_options).
+///
+/// By default every error is collected.
+///
+/// ```
+/// # use validatron::{Validate, ValidationOptions};
+/// #[derive(Validate)]
+/// struct Row(#[validatron(min = 1)] u64);
+///
+/// let rows: Vec<_> = (0..1000).map(|_| Row(0)).collect();
+///
+/// let e = rows
+///     .validate_with_options(ValidationOptions::new().max_errors(10))
+///     .unwrap_err();
+///
+/// assert!(e.is_truncated());
+/// assert_eq!(e.flatten().filter(|(_, r)| r.code() == "min").count(), 10);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// Stop validating once this many errors have been found
+    pub max_errors: Option<usize>,
+    /// Stop validating at the first error, for when only a yes or no answer is needed
+    pub fail_fast: bool,
+}
+
+impl ValidationOptions {
+    /// Options collecting every error
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop validating once this many errors have been found. With `0` nothing is checked
+    /// and validation fails as truncated.
+    pub fn max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
+    /// Stop validating at the first error
+    pub fn fail_fast(mut self) -> Self {
+        self.fail_fast = true;
+        self
+    }
+
+    fn limit(&self) -> Option<usize> {
+        if self.fail_fast {
+            Some(1)
+        } else {
+            self.max_errors
+        }
+    }
+}
+
+/// The number of errors validation may still report before it stops, shared by every
+/// part of a data structure as it is validated.
+///
+/// Implementors of [`Validate::validate_within`](crate::Validate::validate_within) should
+/// [`charge`](Budget::charge) the results of their own checks and stop once
+/// [`stop`](Budget::stop) returns true.
+#[derive(Debug, Clone)]
+pub struct Budget {
+    limit: Option<usize>,
+    remaining: Option<usize>,
+    truncated: bool,
+}
+
+impl Budget {
+    /// A budget for the given options
+    pub fn new(options: ValidationOptions) -> Self {
+        Self {
+            limit: options.limit(),
+            remaining: options.limit(),
+            truncated: false,
+        }
+    }
+
+    /// A budget that is never exhausted
+    pub fn unlimited() -> Self {
+        Self::new(ValidationOptions::new())
+    }
+
+    /// Deduct the errors of a result from the budget, passing the result through
+    pub fn charge(&mut self, result: Result<()>) -> Result<()> {
+        if let (Some(remaining), Err(e)) = (&mut self.remaining, &result) {
+            *remaining = remaining.saturating_sub(e.flatten().count());
+        }
+
+        result
+    }
+
+    /// Whether validation should stop as the budget is exhausted, recording that the
+    /// remaining checks were skipped
+    pub fn stop(&mut self) -> bool {
+        if self.remaining == Some(0) {
+            self.truncated = true;
+        }
+
+        self.truncated
+    }
+
+    /// Whether any checks were skipped as the budget was exhausted
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Complete validation, recording on the error whether it was truncated.
+    ///
+    /// A truncated validation always fails, even if the checks that did run passed.
+    pub fn finish(&self, result: Result<()>) -> Result<()> {
+        let limit = match self.limit {
+            Some(limit) if self.truncated => limit,
+            _ => return result,
+        };
+
+        let truncated = Error::from(
+            Reason::new(
+                Error::TRUNCATED,
+                format!("validation stopped after {} errors", limit),
+            )
+            .with_param("max_errors", limit as u64),
+        );
+
+        match result {
+            Ok(()) => Err(truncated),
+            Err(Error::Structured(mut errors)) => {
+                errors
+                    .entry(Location::Truncated)
+                    .or_insert_with(|| Error::Unstructured(vec![]))
+                    .merge(truncated);
+                Err(Error::Structured(errors))
+            }
+            Err(mut e) => {
+                e.merge(truncated);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Error {
+    /// The code of the reason recording that validation stopped early, see
+    /// [`ValidationOptions`]
+    pub const TRUNCATED: &'static str = "truncated";
+
+    /// Whether validation stopped before checking everything as the error budget of its
+    /// [`ValidationOptions`] was exhausted
+    pub fn is_truncated(&self) -> bool {
+        match self {
+            Error::Unstructured(reasons) => reasons.iter().any(|r| r.code() == Error::TRUNCATED),
+            Error::Structured(errors) => errors.contains_key(&Location::Truncated),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget() {
+        let mut budget = Budget::new(ValidationOptions::new().max_errors(2));
+
+        assert!(!budget.stop());
+        assert!(budget.charge(Ok(())).is_ok());
+        assert!(budget.charge(Err(Error::new("a"))).is_err());
+        assert!(!budget.stop());
+        assert!(budget.charge(Err(Error::new("b"))).is_err());
+        assert!(!budget.is_truncated());
+        assert!(budget.stop());
+        assert!(budget.is_truncated());
+
+        let e = budget.finish(Err(Error::new("a"))).unwrap_err();
+        assert!(e.is_truncated());
+        assert_eq!(e.to_string(), "a\nvalidation stopped after 2 errors");
+    }
+
+    #[test]
+    fn fail_fast_and_unlimited() {
+        let mut budget = Budget::new(ValidationOptions::new().max_errors(5).fail_fast());
+        let _ = budget.charge(Err(Error::new("a")));
+        assert!(budget.stop());
+
+        let mut budget = Budget::unlimited();
+        for _ in 0..10 {
+            let _ = budget.charge(Err(Error::new("a")));
+        }
+        assert!(!budget.stop());
+        assert!(!budget
+            .finish(Err(Error::new("a")))
+            .unwrap_err()
+            .is_truncated());
+    }
+
+    #[test]
+    fn untruncated_results_are_unchanged() {
+        let budget = Budget::new(ValidationOptions::new().fail_fast());
+        assert!(budget.finish(Ok(())).is_ok());
+        assert_eq!(budget.finish(Err(Error::new("a"))), Err(Error::new("a")));
+    }
+
+    #[test]
+    fn zero_max_errors_fails() {
+        let mut budget = Budget::new(ValidationOptions::new().max_errors(0));
+        assert!(budget.stop());
+        assert!(budget.finish(Ok(())).unwrap_err().is_truncated());
+    }
+
+    #[test]
+    fn truncation_does_not_collide_with_fields() {
+        let mut budget = Budget::new(ValidationOptions::new().max_errors(1));
+        let _ = budget.charge(Err(Error::new("a")));
+        assert!(budget.stop());
+
+        let field = || Error::build().at_named("errors", "a").build();
+        let e = budget.finish(field()).unwrap_err();
+        assert!(e.is_truncated());
+        assert!(!field().unwrap_err().is_truncated());
+
+        match &e {
+            Error::Structured(errors) => {
+                assert_eq!(errors[&Location::Named("errors".into())], Error::new("a"));
+            }
+            _ => panic!("should be structured"),
+        }
+        assert_eq!(
+            e.to_string(),
+            "validation stopped after 1 errors\nerrors: a"
+        );
+    }
+}
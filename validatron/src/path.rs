@@ -0,0 +1,604 @@
+use std::{borrow::Cow, collections::btree_map, fmt, str::FromStr};
+use thiserror::Error;
+
+use crate::{Error, Location, Reason};
+
+/// The location of a value within a nested data structure, as a sequence of
+/// [`Location`]s from the root.
+///
+/// Paths can be rendered as, and parsed from:
+///
+/// - an [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901) JSON Pointer, `/a/b/0/c`
+/// - a dotted path, `a.b[0].c`, which is also the [`Display`](fmt::Display) form
+/// - a JSONPath, `$.a.b[0].c`
+///
+/// ```
+/// # use validatron::{path::Path, Location};
+/// let path: Path = vec![
+///     Location::Named("out_d".into()),
+///     Location::Named("a bad example".into()),
+///     Location::Index(0),
+/// ]
+/// .into();
+///
+/// assert_eq!(path.to_json_pointer(), "/out_d/a bad example/0");
+/// assert_eq!(path.to_dotted(), "out_d[\"a bad example\"][0]");
+/// assert_eq!(path.to_json_path(), "$.out_d['a bad example'][0]");
+///
+/// assert_eq!(Path::from_dotted(&path.to_dotted()).unwrap(), path);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Path(Vec<Location>);
+
+/// An error encountered while parsing a [`Path`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid path at offset {offset}: {reason}")]
+pub struct ParsePathError {
+    /// the byte offset into the input at which parsing failed
+    pub offset: usize,
+    /// a description of the problem
+    pub reason: &'static str,
+}
+
+impl Path {
+    /// Construct an empty path, referring to the root of a data structure
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a location to the path
+    pub fn push(&mut self, location: Location) {
+        self.0.push(location);
+    }
+
+    /// Remove the last location from the path, returning it
+    pub fn pop(&mut self) -> Option<Location> {
+        self.0.pop()
+    }
+
+    /// The locations making up the path, from the root
+    pub fn locations(&self) -> &[Location] {
+        &self.0
+    }
+
+    /// Is this the path of the root of a data structure
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Render the path as an RFC 6901 JSON Pointer, escaping `~` as `~0` and `/` as `~1`
+    pub fn to_json_pointer(&self) -> String {
+        let mut out = String::new();
+
+        for location in &self.0 {
+            let segment = match location {
+                Location::Named(name) => name.replace('~', "~0").replace('/', "~1"),
+                Location::Index(i) => i.to_string(),
+                Location::Truncated => continue,
+            };
+
+            out.push('/');
+            out.push_str(&segment);
+        }
+
+        out
+    }
+
+    /// Parse an RFC 6901 JSON Pointer.
+    ///
+    /// JSON Pointers do not distinguish between array indices and object keys, so
+    /// segments that are valid array indices are parsed as [`Location::Index`].
+    ///
+    /// ```
+    /// # use validatron::{path::Path, Location};
+    /// let path = Path::from_json_pointer("/a~1b/0").unwrap();
+    /// assert_eq!(path.locations(), [Location::Named("a/b".into()), Location::Index(0)]);
+    /// ```
+    pub fn from_json_pointer(s: &str) -> Result<Self, ParsePathError> {
+        if s.is_empty() {
+            return Ok(Path::new());
+        }
+
+        if !s.starts_with('/') {
+            return Err(ParsePathError {
+                offset: 0,
+                reason: "a JSON Pointer must start with '/'",
+            });
+        }
+
+        let mut path = Path::new();
+        let mut offset = 1;
+
+        for segment in s[1..].split('/') {
+            let location = if is_array_index(segment) {
+                match segment.parse() {
+                    Ok(i) => Location::Index(i),
+                    Err(_) => Location::Named(segment.to_string().into()),
+                }
+            } else {
+                Location::Named(unescape_pointer(segment, offset)?.into())
+            };
+
+            path.push(location);
+            offset += segment.len() + 1;
+        }
+
+        Ok(path)
+    }
+
+    /// Render the path in dotted form such as `a.b[0].c`, names that are not plain
+    /// identifiers are quoted as `a["b c"]`
+    pub fn to_dotted(&self) -> String {
+        let mut out = String::new();
+
+        for location in &self.0 {
+            match location {
+                Location::Named(name) if is_plain(name) => {
+                    if !out.is_empty() {
+                        out.push('.');
+                    }
+                    out.push_str(name);
+                }
+                Location::Named(name) => push_quoted(&mut out, name, '"'),
+                Location::Index(i) => {
+                    out.push('[');
+                    out.push_str(&i.to_string());
+                    out.push(']');
+                }
+                Location::Truncated => {}
+            }
+        }
+
+        out
+    }
+
+    /// Parse a path in dotted form such as `a.b[0].c` or `a["b c"]`
+    pub fn from_dotted(s: &str) -> Result<Self, ParsePathError> {
+        Parser {
+            input: s,
+            offset: 0,
+        }
+        .segments(true)
+    }
+
+    /// Render the path as a JSONPath such as `$.a.b[0].c`, names that are not plain
+    /// identifiers are quoted as `$.a['b c']`
+    pub fn to_json_path(&self) -> String {
+        let mut out = String::from("$");
+
+        for location in &self.0 {
+            match location {
+                Location::Named(name) if is_plain(name) => {
+                    out.push('.');
+                    out.push_str(name);
+                }
+                Location::Named(name) => push_quoted(&mut out, name, '\''),
+                Location::Index(i) => {
+                    out.push('[');
+                    out.push_str(&i.to_string());
+                    out.push(']');
+                }
+                Location::Truncated => {}
+            }
+        }
+
+        out
+    }
+
+    /// Parse a JSONPath consisting of names and indices, such as `$.a['b c'][0]`
+    pub fn from_json_path(s: &str) -> Result<Self, ParsePathError> {
+        let mut parser = Parser {
+            input: s,
+            offset: 0,
+        };
+
+        if !parser.eat('$') {
+            return Err(parser.error("a JSONPath must start with '$'"));
+        }
+
+        parser.segments(false)
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_dotted())
+    }
+}
+
+impl FromStr for Path {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::from_dotted(s)
+    }
+}
+
+impl From<Vec<Location>> for Path {
+    fn from(locations: Vec<Location>) -> Self {
+        Path(locations)
+    }
+}
+
+impl FromIterator<Location> for Path {
+    fn from_iter<T: IntoIterator<Item = Location>>(iter: T) -> Self {
+        Path(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Path {
+    type Item = Location;
+    type IntoIter = std::vec::IntoIter<Location>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// plain names can be written without quotes in dotted and JSONPath forms
+fn is_plain(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn is_array_index(segment: &str) -> bool {
+    segment == "0"
+        || (!segment.is_empty()
+            && !segment.starts_with('0')
+            && segment.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn unescape_pointer(segment: &str, offset: usize) -> Result<String, ParsePathError> {
+    let mut out = String::with_capacity(segment.len());
+    let mut chars = segment.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '~' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, '0')) => out.push('~'),
+            Some((_, '1')) => out.push('/'),
+            _ => {
+                return Err(ParsePathError {
+                    offset: offset + i,
+                    reason: "'~' must be followed by '0' or '1'",
+                })
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+fn push_quoted(out: &mut String, name: &str, quote: char) {
+    out.push('[');
+    out.push(quote);
+    for c in name.chars() {
+        if c == quote || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push(quote);
+    out.push(']');
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.offset += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, reason: &'static str) -> ParsePathError {
+        ParsePathError {
+            offset: self.offset,
+            reason,
+        }
+    }
+
+    // parses `.name`, `[0]` and `["name"]` segments until the end of the input, the dot
+    // before the first name may be omitted in dotted form
+    fn segments(mut self, leading_name: bool) -> Result<Path, ParsePathError> {
+        let mut path = Path::new();
+
+        if leading_name && self.peek().is_some_and(|c| c != '[') {
+            path.push(self.name()?);
+        }
+
+        while let Some(c) = self.peek() {
+            let location = match c {
+                '.' => {
+                    self.offset += 1;
+                    self.name()?
+                }
+                '[' => {
+                    self.offset += 1;
+                    let location = self.bracketed()?;
+                    if !self.eat(']') {
+                        return Err(self.error("expected ']'"));
+                    }
+                    location
+                }
+                _ => return Err(self.error("expected '.' or '['")),
+            };
+
+            path.push(location);
+        }
+
+        Ok(path)
+    }
+
+    fn name(&mut self) -> Result<Location, ParsePathError> {
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest().len());
+
+        let name = &self.rest()[..len];
+
+        if !is_plain(name) {
+            return Err(self.error("expected a name"));
+        }
+
+        self.offset += len;
+
+        Ok(Location::Named(Cow::Owned(name.to_string())))
+    }
+
+    fn bracketed(&mut self) -> Result<Location, ParsePathError> {
+        let quote = match self.peek() {
+            Some(q @ ('"' | '\'')) => q,
+            _ => {
+                let len = self
+                    .rest()
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(self.rest().len());
+
+                let index = self.rest()[..len]
+                    .parse()
+                    .map_err(|_| self.error("expected an index or a quoted name"))?;
+
+                self.offset += len;
+
+                return Ok(Location::Index(index));
+            }
+        };
+
+        self.offset += 1;
+
+        let mut name = String::new();
+        let mut chars = self.rest().char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => name.push(escaped),
+                    None => break,
+                },
+                c if c == quote => {
+                    self.offset += i + 1;
+                    return Ok(Location::Named(name.into()));
+                }
+                c => name.push(c),
+            }
+        }
+
+        Err(self.error("unterminated quoted name"))
+    }
+}
+
+enum Frame<'a> {
+    Unstructured(std::slice::Iter<'a, Reason>),
+    Structured(btree_map::Iter<'a, Location, Error>),
+}
+
+impl<'a> Frame<'a> {
+    fn new(error: &'a Error) -> Self {
+        match error {
+            Error::Unstructured(reasons) => Frame::Unstructured(reasons.iter()),
+            Error::Structured(errors) => Frame::Structured(errors.iter()),
+        }
+    }
+}
+
+/// An iterator over every failure reason in an [`enum@Error`] and its path, see
+/// [`Error::flatten`]
+pub struct Flatten<'a> {
+    stack: Vec<(Option<&'a Location>, Frame<'a>)>,
+}
+
+impl<'a> Iterator for Flatten<'a> {
+    type Item = (Path, &'a Reason);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (_, frame) = self.stack.last_mut()?;
+
+            match frame {
+                Frame::Unstructured(reasons) => match reasons.next() {
+                    Some(reason) => {
+                        let path = self
+                            .stack
+                            .iter()
+                            .filter_map(|(location, _)| location.cloned())
+                            .filter(|location| *location != Location::Truncated)
+                            .collect();
+
+                        return Some((path, reason));
+                    }
+                    None => {
+                        self.stack.pop();
+                    }
+                },
+                Frame::Structured(errors) => match errors.next() {
+                    Some((location, error)) => self.stack.push((Some(location), Frame::new(error))),
+                    None => {
+                        self.stack.pop();
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl Error {
+    /// Iterate over every failure reason in the error along with its path
+    ///
+    /// ```
+    /// # use validatron::Error;
+    /// let e = Error::build()
+    ///     .try_at_named("a", Error::build().at_index(1, "broken").build())
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// let flat: Vec<_> = e
+    ///     .flatten()
+    ///     .map(|(path, reason)| (path.to_json_pointer(), reason.message().to_string()))
+    ///     .collect();
+    ///
+    /// assert_eq!(flat, vec![("/a/1".to_string(), "broken".to_string())]);
+    /// ```
+    pub fn flatten(&self) -> Flatten<'_> {
+        Flatten {
+            stack: vec![(None, Frame::new(self))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(locations: &[Location]) -> Path {
+        locations.iter().cloned().collect()
+    }
+
+    fn named(name: &'static str) -> Location {
+        Location::Named(name.into())
+    }
+
+    #[test]
+    fn render() {
+        let p = path(&[named("a"), Location::Index(0), named("b/c~d"), named("0")]);
+
+        assert_eq!(p.to_json_pointer(), "/a/0/b~1c~0d/0");
+        assert_eq!(p.to_dotted(), r#"a[0]["b/c~d"]["0"]"#);
+        assert_eq!(p.to_json_path(), "$.a[0]['b/c~d']['0']");
+        assert_eq!(p.to_string(), p.to_dotted());
+
+        let root = Path::new();
+        assert_eq!(root.to_json_pointer(), "");
+        assert_eq!(root.to_dotted(), "");
+        assert_eq!(root.to_json_path(), "$");
+    }
+
+    #[test]
+    fn round_trip() {
+        let paths = vec![
+            Path::new(),
+            path(&[named("a")]),
+            path(&[Location::Index(3), named("a"), named("b")]),
+            path(&[
+                named("quote\"s and 'single' \\ slashes"),
+                Location::Index(10),
+            ]),
+            path(&[named(""), named("ünïcödé"), named("in-a"), named("1a")]),
+        ];
+
+        for p in paths {
+            assert_eq!(Path::from_dotted(&p.to_dotted()), Ok(p.clone()));
+            assert_eq!(p.to_string().parse(), Ok(p.clone()));
+            assert_eq!(Path::from_json_path(&p.to_json_path()), Ok(p.clone()));
+            assert_eq!(Path::from_json_pointer(&p.to_json_pointer()), Ok(p.clone()));
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Path::from_json_pointer("/a/01/~01"),
+            Ok(path(&[named("a"), named("01"), named("~1")]))
+        );
+        assert_eq!(
+            Path::from_json_path("$['a'][\"b\"].c"),
+            Ok(path(&[named("a"), named("b"), named("c")]))
+        );
+
+        assert!(Path::from_json_pointer("a").is_err());
+        assert!(Path::from_json_pointer("/~2").is_err());
+        assert!(Path::from_json_path("a.b").is_err());
+        assert!(Path::from_dotted("a..b").is_err());
+        assert!(Path::from_dotted("a[b]").is_err());
+        assert!(Path::from_dotted("a[\"b").is_err());
+        assert!(Path::from_dotted("a[0").is_err());
+        assert_eq!(
+            Path::from_dotted("a b"),
+            Err(ParsePathError {
+                offset: 1,
+                reason: "expected '.' or '['"
+            })
+        );
+    }
+
+    #[test]
+    fn flatten() {
+        let e = Error::build()
+            .at_named("a", "first")
+            .at_named("a", "second")
+            .try_at_named(
+                "b",
+                Error::build()
+                    .at_index(0, "third")
+                    .try_at_index(1, Error::build().at_named("c", "fourth").build())
+                    .build(),
+            )
+            .build()
+            .unwrap_err();
+
+        let flat: Vec<_> = e
+            .flatten()
+            .map(|(path, reason)| (path.to_json_pointer(), reason.message().into_owned()))
+            .collect();
+
+        assert_eq!(
+            flat,
+            vec![
+                ("/a".to_string(), "first".to_string()),
+                ("/a".to_string(), "second".to_string()),
+                ("/b/0".to_string(), "third".to_string()),
+                ("/b/1/c".to_string(), "fourth".to_string()),
+            ]
+        );
+
+        let root = Error::new("root");
+        let root: Vec<_> = root.flatten().collect();
+        assert_eq!(root.len(), 1);
+        assert!(root[0].0.is_empty());
+    }
+}
@@ -0,0 +1,343 @@
+use std::fmt;
+
+use crate::{Error, Location, Path};
+
+/// The layout used by a [`Renderer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// One `path: message` line per failure
+    Lines,
+    /// An indented tree of locations with their failures beneath them
+    Tree,
+    /// A single line of `path: message` entries separated by `; `
+    Compact,
+}
+
+/// A configurable [`Display`](fmt::Display) implementation for [`enum@Error`]s, created
+/// with [`Error::render`].
+///
+/// ```
+/// # use validatron::{render::Style, Error};
+/// let e = Error::build()
+///     .try_at_named("a", Error::build().at_index(0, "too small").build())
+///     .at_named("b", "missing")
+///     .build()
+///     .unwrap_err();
+///
+/// assert_eq!(e.to_string(), "a[0]: too small\nb: missing");
+/// assert_eq!(format!("{:#}", e), "a[0]: too small; b: missing");
+/// assert_eq!(
+///     e.render().style(Style::Tree).to_string(),
+///     "a:\n  [0]:\n    - too small\nb:\n  - missing"
+/// );
+/// assert_eq!(e.render().max_depth(1).to_string(), "a: 1 nested error\nb: missing");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Renderer<'a> {
+    error: &'a Error,
+    style: Style,
+    max_depth: Option<usize>,
+    max_lines: Option<usize>,
+    indent: usize,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(error: &'a Error) -> Self {
+        Self {
+            error,
+            style: Style::Lines,
+            max_depth: None,
+            max_lines: None,
+            indent: 2,
+        }
+    }
+
+    /// Set the layout of the output, [`Style::Lines`] by default
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Summarise failures nested deeper than the given number of locations
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Limit the number of lines (or entries in the [`Style::Compact`] form), the failures
+    /// that are omitted are counted in a final line
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// The number of spaces to indent each level of a [`Style::Tree`] by, 2 by default
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    fn depth_exceeded(&self, depth: usize) -> bool {
+        self.max_depth.is_some_and(|max| depth >= max)
+    }
+
+    // `path: message` entries for the lines and compact styles
+    fn entries(&self, error: &Error, path: &mut Path, out: &mut Lines) {
+        let entry = |path: &Path, text: &dyn fmt::Display| {
+            if path.is_empty() {
+                text.to_string()
+            } else {
+                format!("{}: {}", path, text)
+            }
+        };
+
+        match error {
+            Error::Unstructured(reasons) => {
+                for reason in reasons {
+                    if out.is_full() {
+                        return;
+                    }
+
+                    out.push(entry(path, reason), 1);
+                }
+            }
+            Error::Structured(_) if self.depth_exceeded(path.locations().len()) => {
+                if !out.is_full() {
+                    let (summary, failures) = nested_summary(error);
+                    out.push(entry(path, &summary), failures);
+                }
+            }
+            Error::Structured(errors) => {
+                for (location, error) in errors {
+                    if out.is_full() {
+                        return;
+                    }
+
+                    if *location == Location::Truncated {
+                        self.entries(error, path, out);
+                        continue;
+                    }
+
+                    path.push(location.clone());
+                    self.entries(error, path, out);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    fn tree(&self, error: &Error, depth: usize, out: &mut Lines) {
+        let pad = " ".repeat(depth * self.indent);
+
+        match error {
+            Error::Unstructured(reasons) => {
+                for reason in reasons {
+                    if out.is_full() {
+                        return;
+                    }
+
+                    out.push(format!("{}- {}", pad, reason), 1);
+                }
+            }
+            Error::Structured(_) if self.depth_exceeded(depth) => {
+                if !out.is_full() {
+                    let (summary, failures) = nested_summary(error);
+                    out.push(format!("{}- {}", pad, summary), failures);
+                }
+            }
+            Error::Structured(errors) => {
+                for (location, error) in errors {
+                    if out.is_full() {
+                        return;
+                    }
+
+                    if *location == Location::Truncated {
+                        self.tree(error, depth, out);
+                        continue;
+                    }
+
+                    out.push(format!("{}{}:", pad, label(location)), 0);
+                    self.tree(error, depth + 1, out);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Renderer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut lines = Lines {
+            lines: vec![],
+            max_lines: self.max_lines,
+            shown: 0,
+        };
+
+        match self.style {
+            Style::Lines | Style::Compact => self.entries(self.error, &mut Path::new(), &mut lines),
+            Style::Tree => self.tree(self.error, 0, &mut lines),
+        }
+
+        if lines.is_full() {
+            // the remaining failures are counted rather than rendered
+            let omitted = self.error.flatten().count().saturating_sub(lines.shown);
+
+            if omitted > 0 {
+                lines.lines.push(format!("... {} more", omitted));
+            }
+        }
+
+        let separator = match self.style {
+            Style::Compact => "; ",
+            Style::Lines | Style::Tree => "\n",
+        };
+
+        f.write_str(&lines.lines.join(separator))
+    }
+}
+
+// the rendered lines, which are no longer collected once there are `max_lines` of them
+struct Lines {
+    lines: Vec<String>,
+    max_lines: Option<usize>,
+    // the number of failures in the lines, so that those omitted can be counted
+    shown: usize,
+}
+
+impl Lines {
+    fn is_full(&self) -> bool {
+        self.max_lines.is_some_and(|max| self.lines.len() >= max)
+    }
+
+    fn push(&mut self, line: String, failures: usize) {
+        self.lines.push(line);
+        self.shown += failures;
+    }
+}
+
+fn label(location: &Location) -> String {
+    Path::from(vec![location.clone()]).to_dotted()
+}
+
+// a summary of the failures nested beneath a location, and their number
+fn nested_summary(error: &Error) -> (String, usize) {
+    match error.flatten().count() {
+        1 => (String::from("1 nested error"), 1),
+        n => (format!("{} nested errors", n), n),
+    }
+}
+
+impl Error {
+    /// Configure how the error is displayed, see [`Renderer`]
+    pub fn render(&self) -> Renderer<'_> {
+        Renderer::new(self)
+    }
+}
+
+/// Errors are displayed as one `path: message` line per failure, or on a single line
+/// with the alternate `{:#}` flag.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = if f.alternate() {
+            Style::Compact
+        } else {
+            Style::Lines
+        };
+
+        self.render().style(style).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Error {
+        Error::build()
+            .at_named("a", "first")
+            .at_named("a", "second")
+            .try_at_named(
+                "b c",
+                Error::build()
+                    .at_index(0, "third")
+                    .try_at_index(1, Error::build().at_named("d", "fourth").build())
+                    .build(),
+            )
+            .build()
+            .unwrap_err()
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            example().to_string(),
+            "a: first\na: second\n[\"b c\"][0]: third\n[\"b c\"][1].d: fourth"
+        );
+        assert_eq!(Error::new("root").to_string(), "root");
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(
+            format!("{:#}", example()),
+            "a: first; a: second; [\"b c\"][0]: third; [\"b c\"][1].d: fourth"
+        );
+    }
+
+    #[test]
+    fn tree() {
+        let e = example();
+
+        assert_eq!(
+            e.render().style(Style::Tree).to_string(),
+            [
+                "a:",
+                "  - first",
+                "  - second",
+                "[\"b c\"]:",
+                "  [0]:",
+                "    - third",
+                "  [1]:",
+                "    d:",
+                "      - fourth",
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(
+            e.render()
+                .style(Style::Tree)
+                .indent(1)
+                .max_depth(1)
+                .to_string(),
+            "a:\n - first\n - second\n[\"b c\"]:\n - 2 nested errors"
+        );
+    }
+
+    #[test]
+    fn limits() {
+        let e = example();
+
+        assert_eq!(
+            e.render().max_depth(2).to_string(),
+            "a: first\na: second\n[\"b c\"][0]: third\n[\"b c\"][1]: 1 nested error"
+        );
+        assert_eq!(e.render().max_depth(0).to_string(), "4 nested errors");
+        assert_eq!(
+            e.render().max_lines(2).to_string(),
+            "a: first\na: second\n... 2 more"
+        );
+        assert_eq!(
+            e.render().style(Style::Compact).max_lines(1).to_string(),
+            "a: first; ... 3 more"
+        );
+        assert_eq!(
+            e.render().style(Style::Tree).max_lines(4).to_string(),
+            "a:\n  - first\n  - second\n[\"b c\"]:\n... 2 more"
+        );
+        assert_eq!(
+            e.render().max_depth(1).max_lines(1).to_string(),
+            "a: first\n... 3 more"
+        );
+        assert_eq!(e.render().max_lines(4).to_string(), e.to_string());
+    }
+}
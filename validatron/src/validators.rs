@@ -1,5 +1,9 @@
-use crate::{Error, Result};
-use std::fmt::Display;
+use crate::length::{self, Len};
+use crate::{checks, formats, Collection, HasLength, Number, Param, Reason, Result};
+use std::collections::hash_map::{Entry, HashMap};
+use std::fmt::{Debug, Display, Write};
+use std::hash::Hash;
+use std::ops::{Bound, RangeBounds};
 
 /// Check that an option has a value
 ///
@@ -12,10 +16,10 @@ use std::fmt::Display;
 /// assert!(is_required::<i64>(&y).is_err());
 /// ```
 pub fn is_required<T>(value: &Option<T>) -> Result<()> {
-    if value.is_none() {
-        Err(Error::new("Option is required to have a value"))
-    } else {
+    if checks::is_required(value) {
         Ok(())
+    } else {
+        Err(Reason::from_code("required").into())
     }
 }
 
@@ -32,32 +36,156 @@ where
     L: PartialEq<R> + Display,
     R: Display,
 {
-    if *value == other {
+    let param = Param::display(&other);
+
+    if checks::is_equal(value, other) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("equal")
+            .with_param("equal", param)
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+// the allowed or forbidden values of a list, as named in messages
+fn value_list<A>(values: &[A]) -> String
+where
+    A: Display,
+{
+    let mut list = String::new();
+
+    for (i, x) in values.iter().enumerate() {
+        if i > 0 {
+            list.push_str(", ");
+        }
+
+        // writing to a string cannot fail
+        let _ = write!(list, "{}", x);
+    }
+
+    list
+}
+
+/// Check that a value equals one of a list of allowed values
+///
+/// Failures list the allowed values as the `one_of` parameter.
+///
+/// ```
+/// # use validatron::validators::one_of;
+/// let mode = String::from("safe");
+/// assert!(one_of(&mode, &["nominal", "safe", "eclipse"]).is_ok());
+/// assert!(one_of(&3, &[1, 2]).is_err());
+/// ```
+pub fn one_of<T, A>(value: &T, allowed: &[A]) -> Result<()>
+where
+    T: PartialEq<A> + Display,
+    A: Display,
+{
+    if checks::one_of(value, allowed) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("one_of")
+            .with_param("one_of", value_list(allowed))
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that a value equals none of a list of forbidden values
+///
+/// ```
+/// # use validatron::validators::none_of;
+/// assert!(none_of(&"gs-1", &["localhost", "test"]).is_ok());
+/// assert!(none_of(&0, &[0]).is_err());
+/// ```
+pub fn none_of<T, A>(value: &T, forbidden: &[A]) -> Result<()>
+where
+    T: PartialEq<A> + Display,
+    A: Display,
+{
+    if checks::none_of(value, forbidden) {
         Ok(())
     } else {
-        Err(Error::new(format!("'{}' must equal '{}'", value, other)))
+        Err(Reason::from_code("none_of")
+            .with_param("none_of", value_list(forbidden))
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that text equals one of a list of allowed values, ignoring case
+///
+/// Text is compared by the lowercase forms of its characters, so that `"SAFE"` and `"Safe"`
+/// are both allowed by `"safe"`.
+///
+/// ```
+/// # use validatron::validators::one_of_ignore_case;
+/// assert!(one_of_ignore_case("Eclipse", &["nominal", "safe", "eclipse"]).is_ok());
+/// assert!(one_of_ignore_case("off", &["nominal", "safe", "eclipse"]).is_err());
+/// ```
+pub fn one_of_ignore_case<T, A>(value: &T, allowed: &[A]) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+    A: AsRef<str> + Display,
+{
+    if checks::one_of_ignore_case(value, allowed) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("one_of")
+            .with_param("one_of", value_list(allowed))
+            .with_param("value", value.as_ref().to_string())
+            .into())
+    }
+}
+
+/// Check that text equals none of a list of forbidden values, ignoring case
+///
+/// ```
+/// # use validatron::validators::none_of_ignore_case;
+/// assert!(none_of_ignore_case("gs-1", &["localhost"]).is_ok());
+/// assert!(none_of_ignore_case("LocalHost", &["localhost"]).is_err());
+/// ```
+pub fn none_of_ignore_case<T, A>(value: &T, forbidden: &[A]) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+    A: AsRef<str> + Display,
+{
+    if checks::none_of_ignore_case(value, forbidden) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("none_of")
+            .with_param("none_of", value_list(forbidden))
+            .with_param("value", value.as_ref().to_string())
+            .into())
     }
 }
 
 /// Check that a value is greater than a value
 ///
+/// Values which cannot be compared with the minimum, such as NaN, fail, see
+/// [`min_allow_nan`] to let NaN through.
+///
 /// ```
 /// # use validatron::validators::min;
 /// assert!(min(&42, 0).is_ok());
 /// assert!(min(&1.0, 2.0).is_err());
+/// assert!(min(&f64::NAN, 2.0).is_err());
 /// ```
 pub fn min<L, R>(value: &L, min: R) -> Result<()>
 where
     L: PartialOrd<R> + Display,
     R: Display,
 {
-    if *value < min {
-        Err(Error::new(format!(
-            "'{}' must be greater than or equal to '{}'",
-            value, min
-        )))
-    } else {
+    let param = Param::display(&min);
+
+    if checks::min(value, min) {
         Ok(())
+    } else {
+        Err(Reason::from_code("min")
+            .with_param("min", param)
+            .with_param("value", Param::display(value))
+            .into())
     }
 }
 
@@ -83,8 +211,33 @@ where
     }
 }
 
+/// Check that a value is greater than a value, or NaN, as `min` with `allow_nan` does
+///
+/// Only values which cannot be compared with themselves pass, other values which cannot be
+/// compared with the minimum still fail.
+///
+/// ```
+/// # use validatron::validators::min_allow_nan;
+/// assert!(min_allow_nan(&f64::NAN, 2.0).is_ok());
+/// assert!(min_allow_nan(&1.0, 2.0).is_err());
+/// ```
+pub fn min_allow_nan<L, R>(value: &L, min_value: R) -> Result<()>
+where
+    L: PartialOrd + PartialOrd<R> + Display,
+    R: Display,
+{
+    if checks::not_nan(value) {
+        min(value, min_value)
+    } else {
+        Ok(())
+    }
+}
+
 /// Check that a value is less than a max
 ///
+/// Values which cannot be compared with the maximum, such as NaN, fail, see
+/// [`max_allow_nan`] to let NaN through.
+///
 /// ```
 /// # use validatron::validators::max;
 /// assert!(max(&42, 128).is_ok());
@@ -95,13 +248,15 @@ where
     L: PartialOrd<R> + Display,
     R: Display,
 {
-    if *value > max {
-        Err(Error::new(format!(
-            "'{}' must be less than or equal to '{}'",
-            value, max
-        )))
-    } else {
+    let param = Param::display(&max);
+
+    if checks::max(value, max) {
         Ok(())
+    } else {
+        Err(Reason::from_code("max")
+            .with_param("max", param)
+            .with_param("value", Param::display(value))
+            .into())
     }
 }
 
@@ -127,15 +282,404 @@ where
     }
 }
 
-fn sequence_length<C>(iterable: C) -> usize
+/// Check that a value is less than a max, or NaN, as `max` with `allow_nan` does
+///
+/// Only values which cannot be compared with themselves pass, other values which cannot be
+/// compared with the maximum still fail.
+///
+/// ```
+/// # use validatron::validators::max_allow_nan;
+/// assert!(max_allow_nan(&f64::NAN, 1.0).is_ok());
+/// assert!(max_allow_nan(&2.0, 1.0).is_err());
+/// ```
+pub fn max_allow_nan<L, R>(value: &L, max_value: R) -> Result<()>
 where
-    C: IntoIterator,
+    L: PartialOrd + PartialOrd<R> + Display,
+    R: Display,
+{
+    if checks::not_nan(value) {
+        max(value, max_value)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a value is greater than a value, excluding the value itself
+///
+/// ```
+/// # use validatron::validators::exclusive_min;
+/// assert!(exclusive_min(&0.5, 0.0).is_ok());
+/// assert!(exclusive_min(&0.0, 0.0).is_err());
+/// ```
+pub fn exclusive_min<L, R>(value: &L, min: R) -> Result<()>
+where
+    L: PartialOrd<R> + Display,
+    R: Display,
+{
+    let param = Param::display(&min);
+
+    if checks::exclusive_min(value, min) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("exclusive_min")
+            .with_param("exclusive_min", param)
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that an optional value is either none or greater than a value, excluding the
+/// value itself
+pub fn option_exclusive_min<L, R>(value: &Option<L>, min_value: R) -> Result<()>
+where
+    L: PartialOrd<R> + Display,
+    R: Display,
+{
+    if let Some(x) = value {
+        exclusive_min(x, min_value)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a value is less than a value, excluding the value itself
+///
+/// ```
+/// # use validatron::validators::exclusive_max;
+/// assert!(exclusive_max(&359, 360).is_ok());
+/// assert!(exclusive_max(&360, 360).is_err());
+/// ```
+pub fn exclusive_max<L, R>(value: &L, max: R) -> Result<()>
+where
+    L: PartialOrd<R> + Display,
+    R: Display,
+{
+    let param = Param::display(&max);
+
+    if checks::exclusive_max(value, max) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("exclusive_max")
+            .with_param("exclusive_max", param)
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that an optional value is either none or less than a value, excluding the value
+/// itself
+pub fn option_exclusive_max<L, R>(value: &Option<L>, max_value: R) -> Result<()>
+where
+    L: PartialOrd<R> + Display,
+    R: Display,
+{
+    if let Some(x) = value {
+        exclusive_max(x, max_value)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a value is within a range, whose bounds may each be inclusive, exclusive or
+/// unbounded
+///
+/// Failures report the range in interval notation, such as `[0, 360)`. Values which cannot
+/// be compared with a bound, such as NaN, fail, see [`range_allow_nan`] to let NaN through.
+///
+/// ```
+/// # use validatron::validators::range;
+/// use std::ops::Bound::{Excluded, Included};
+///
+/// assert!(range(&0, 0..360).is_ok());
+/// assert!(range(&360, 0..360).is_err());
+/// assert!(range(&0.0, (Excluded(0.0), Included(1.0))).is_err());
+/// ```
+pub fn range<L, R, B>(value: &L, range: B) -> Result<()>
+where
+    L: PartialOrd<R> + Display,
+    R: Display,
+    B: RangeBounds<R>,
+{
+    if checks::range(value, (range.start_bound(), range.end_bound())) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("range")
+            .with_param("range", interval(&range))
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that an optional value is either none or within a range
+pub fn option_range<L, R, B>(value: &Option<L>, bounds: B) -> Result<()>
+where
+    L: PartialOrd<R> + Display,
+    R: Display,
+    B: RangeBounds<R>,
+{
+    if let Some(x) = value {
+        range(x, bounds)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a value is within a range, or NaN, as `range` with `allow_nan` does
+///
+/// Only values which cannot be compared with themselves pass, other values which cannot be
+/// compared with a bound still fail.
+///
+/// ```
+/// # use validatron::validators::range_allow_nan;
+/// assert!(range_allow_nan(&f64::NAN, 0.0..1.0).is_ok());
+/// assert!(range_allow_nan(&1.0, 0.0..1.0).is_err());
+/// ```
+pub fn range_allow_nan<L, R, B>(value: &L, bounds: B) -> Result<()>
+where
+    L: PartialOrd + PartialOrd<R> + Display,
+    R: Display,
+    B: RangeBounds<R>,
+{
+    if checks::not_nan(value) {
+        range(value, bounds)
+    } else {
+        Ok(())
+    }
+}
+
+// a range in interval notation, such as [0, 360) or (-∞, 1]
+fn interval<R, B>(range: &B) -> String
+where
+    R: Display,
+    B: RangeBounds<R>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(x) => format!("[{}", x),
+        Bound::Excluded(x) => format!("({}", x),
+        Bound::Unbounded => String::from("(-∞"),
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(x) => format!("{}]", x),
+        Bound::Excluded(x) => format!("{})", x),
+        Bound::Unbounded => String::from("∞)"),
+    };
+
+    format!("{}, {}", start, end)
+}
+
+/// Check that a value is comparable with itself, which floats are unless they are NaN
+///
+/// ```
+/// # use validatron::validators::not_nan;
+/// assert!(not_nan(&1.5).is_ok());
+/// assert!(not_nan(&Some(f64::NAN)).is_err());
+/// assert!(not_nan(&None::<f64>).is_ok());
+/// ```
+pub fn not_nan<T>(value: &T) -> Result<()>
+where
+    T: PartialOrd,
+{
+    if checks::not_nan(value) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("not_nan").into())
+    }
+}
+
+/// Check that a number is neither infinite nor NaN
+///
+/// ```
+/// # use validatron::validators::finite;
+/// assert!(finite(&1.5).is_ok());
+/// assert!(finite(&f64::INFINITY).is_err());
+/// assert!(finite(&f32::NAN).is_err());
+/// ```
+pub fn finite<T>(value: &T) -> Result<()>
+where
+    T: Number,
+{
+    if checks::finite(value) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("finite")
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that a number is a multiple of a factor, see [`Number::is_multiple_of`]
+///
+/// ```
+/// # use validatron::validators::multiple_of;
+/// assert!(multiple_of(&15, 5).is_ok());
+/// assert!(multiple_of(&0.3, 0.1).is_ok());
+/// assert!(multiple_of(&0.35, 0.1).is_err());
+/// ```
+pub fn multiple_of<T>(value: &T, factor: T) -> Result<()>
+where
+    T: Number,
+{
+    if checks::multiple_of(value, factor) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("multiple_of")
+            .with_param("multiple_of", Param::display(&factor))
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that a number has at most a number of decimal places, as displayed
+///
+/// ```
+/// # use validatron::validators::max_decimal_places;
+/// assert!(max_decimal_places(&12.25, 2).is_ok());
+/// assert!(max_decimal_places(&12.125, 2).is_err());
+/// ```
+pub fn max_decimal_places<T>(value: &T, places: usize) -> Result<()>
+where
+    T: Number,
+{
+    if checks::max_decimal_places(value, places) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("max_decimal_places")
+            .with_param("max_decimal_places", places as u64)
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+// shared by the cross-field comparisons, which name both fields in their messages
+fn compare_fields<L, R>(
+    code: &'static str,
+    holds: bool,
+    (value, field): (&L, &str),
+    (other, other_field): (&R, &str),
+) -> Result<()>
+where
+    L: Display + ?Sized,
+    R: Display + ?Sized,
+{
+    if holds {
+        Ok(())
+    } else {
+        Err(Reason::from_code(code)
+            .with_param("field", field.to_string())
+            .with_param("other_field", other_field.to_string())
+            .with_param("other", Param::display(other))
+            .with_param("value", Param::display(value))
+            .into())
+    }
+}
+
+/// Check that the value of a field equals the value of another field
+///
+/// ```
+/// # use validatron::validators::eq_field;
+/// assert!(eq_field(&"a", "password_confirmation", &"a", "password").is_ok());
+/// assert!(eq_field(&"a", "password_confirmation", &"b", "password").is_err());
+/// ```
+pub fn eq_field<L, R>(value: &L, field: &str, other: &R, other_field: &str) -> Result<()>
+where
+    L: PartialEq<R> + Display + ?Sized,
+    R: Display + ?Sized,
+{
+    compare_fields(
+        "eq_field",
+        checks::eq_field(value, other),
+        (value, field),
+        (other, other_field),
+    )
+}
+
+/// Check that the value of a field is greater than the value of another field
+///
+/// ```
+/// # use validatron::validators::gt_field;
+/// assert!(gt_field(&2, "end", &1, "start").is_ok());
+/// assert!(gt_field(&1, "end", &1, "start").is_err());
+/// ```
+pub fn gt_field<L, R>(value: &L, field: &str, other: &R, other_field: &str) -> Result<()>
+where
+    L: PartialOrd<R> + Display + ?Sized,
+    R: Display + ?Sized,
+{
+    compare_fields(
+        "gt_field",
+        checks::gt_field(value, other),
+        (value, field),
+        (other, other_field),
+    )
+}
+
+/// Check that the value of a field is greater than or equal to the value of another field
+///
+/// ```
+/// # use validatron::validators::gte_field;
+/// assert!(gte_field(&1, "end", &1, "start").is_ok());
+/// assert!(gte_field(&0, "end", &1, "start").is_err());
+/// ```
+pub fn gte_field<L, R>(value: &L, field: &str, other: &R, other_field: &str) -> Result<()>
+where
+    L: PartialOrd<R> + Display + ?Sized,
+    R: Display + ?Sized,
+{
+    compare_fields(
+        "gte_field",
+        checks::gte_field(value, other),
+        (value, field),
+        (other, other_field),
+    )
+}
+
+/// Check that the value of a field is less than the value of another field
+///
+/// ```
+/// # use validatron::validators::lt_field;
+/// assert!(lt_field(&1, "start", &2, "end").is_ok());
+/// assert!(lt_field(&2, "start", &2, "end").is_err());
+/// ```
+pub fn lt_field<L, R>(value: &L, field: &str, other: &R, other_field: &str) -> Result<()>
+where
+    L: PartialOrd<R> + Display + ?Sized,
+    R: Display + ?Sized,
+{
+    compare_fields(
+        "lt_field",
+        checks::lt_field(value, other),
+        (value, field),
+        (other, other_field),
+    )
+}
+
+/// Check that the value of a field is less than or equal to the value of another field
+///
+/// ```
+/// # use validatron::validators::lte_field;
+/// assert!(lte_field(&2, "start", &2, "end").is_ok());
+/// assert!(lte_field(&3, "start", &2, "end").is_err());
+/// ```
+pub fn lte_field<L, R>(value: &L, field: &str, other: &R, other_field: &str) -> Result<()>
+where
+    L: PartialOrd<R> + Display + ?Sized,
+    R: Display + ?Sized,
 {
-    iterable.into_iter().count()
+    compare_fields(
+        "lte_field",
+        checks::lte_field(value, other),
+        (value, field),
+        (other, other_field),
+    )
 }
 
 /// Check that a sequence is at least a certain length
 ///
+/// Iterables whose iterator reports an exact size hint, such as the std collections, are
+/// measured without iterating over them, others by counting their items. Values measured
+/// through [`HasLength`] may be passed as a [`Len`](crate::length::Len).
+///
 /// ```
 /// # use validatron::validators::is_min_length;
 /// let x = vec![1,2,3,4,5];
@@ -147,15 +691,15 @@ pub fn is_min_length<C>(iterable: C, min_length: usize) -> Result<()>
 where
     C: IntoIterator,
 {
-    let len = sequence_length(iterable);
+    let len = length::count(iterable);
 
-    if len < min_length {
-        Err(Error::new(format!(
-            "sequence does not have enough elements, it has {} but the minimum is {}",
-            len, min_length
-        )))
-    } else {
+    if checks::is_min_length(Len(len), min_length) {
         Ok(())
+    } else {
+        Err(Reason::from_code("min_len")
+            .with_param("min_len", min_length as u64)
+            .with_param("len", len as u64)
+            .into())
     }
 }
 
@@ -172,15 +716,646 @@ pub fn is_max_length<C>(iterable: C, max_length: usize) -> Result<()>
 where
     C: IntoIterator,
 {
-    let len = sequence_length(iterable);
+    let len = length::count(iterable);
 
-    if len > max_length {
-        Err(Error::new(format!(
-            "sequence has too many elements, it has {} but the maximum is {}",
-            len, max_length
-        )))
+    if checks::is_max_length(Len(len), max_length) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("max_len")
+            .with_param("max_len", max_length as u64)
+            .with_param("len", len as u64)
+            .into())
+    }
+}
+
+/// Check that a sequence has exactly a certain length
+///
+/// ```
+/// # use validatron::validators::is_exact_length;
+/// let x = vec![1,2,3];
+/// assert!(is_exact_length(&x, 3).is_ok());
+/// assert!(is_exact_length(&x, 2).is_err());
+/// ```
+pub fn is_exact_length<C>(iterable: C, exact_length: usize) -> Result<()>
+where
+    C: IntoIterator,
+{
+    let len = length::count(iterable);
+
+    if checks::is_exact_length(Len(len), exact_length) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("exact_len")
+            .with_param("exact_len", exact_length as u64)
+            .with_param("len", len as u64)
+            .into())
+    }
+}
+
+/// Check that the length of a sequence is within a range
+///
+/// ```
+/// # use validatron::validators::is_length_in_range;
+/// let x = vec![1,2,3];
+/// assert!(is_length_in_range(&x, 1..=3).is_ok());
+/// assert!(is_length_in_range(&x, ..3).is_err());
+/// ```
+pub fn is_length_in_range<C, R>(iterable: C, range: R) -> Result<()>
+where
+    C: IntoIterator,
+    R: RangeBounds<usize> + Debug,
+{
+    let len = length::count(iterable);
+
+    if checks::is_length_in_range(Len(len), (range.start_bound(), range.end_bound())) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("len_range")
+            .with_param("len_range", format!("{:?}", range))
+            .with_param("len", len as u64)
+            .into())
+    }
+}
+
+/// The unit the length of text is counted in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// UTF-8 encoded bytes, as given by [`str::len`]
+    Bytes,
+    /// Unicode scalar values, as given by [`str::chars`]
+    Chars,
+    /// Extended grapheme clusters, the characters a reader perceives, such as `"é"` written
+    /// as an `e` and a combining accent
+    #[cfg(feature = "use-unicode-segmentation")]
+    Graphemes,
+}
+
+impl Unit {
+    /// The length of the text in this unit
+    ///
+    /// ```
+    /// # use validatron::validators::Unit;
+    /// assert_eq!(Unit::Bytes.count("año"), 4);
+    /// assert_eq!(Unit::Chars.count("año"), 3);
+    /// ```
+    pub fn count(&self, text: &str) -> usize {
+        match self {
+            Unit::Bytes => text.len(),
+            Unit::Chars => text.chars().count(),
+            #[cfg(feature = "use-unicode-segmentation")]
+            Unit::Graphemes => {
+                unicode_segmentation::UnicodeSegmentation::graphemes(text, true).count()
+            }
+        }
+    }
+
+    /// The name of the unit, as given to the `unit` attribute
+    pub fn name(&self) -> &'static str {
+        match self {
+            Unit::Bytes => "bytes",
+            Unit::Chars => "chars",
+            #[cfg(feature = "use-unicode-segmentation")]
+            Unit::Graphemes => "graphemes",
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<Unit> for Param {
+    fn from(unit: Unit) -> Self {
+        Param::Str(unit.name().into())
+    }
+}
+
+/// Check that text is at least a certain length
+///
+/// `String`s and `Cow<str>`s are accepted by reference, as they dereference to `str`.
+///
+/// ```
+/// # use validatron::validators::{is_min_text_length, Unit};
+/// let x = String::from("año");
+/// assert!(is_min_text_length(&x, 4, Unit::Bytes).is_ok());
+/// assert!(is_min_text_length(&x, 4, Unit::Chars).is_err());
+/// ```
+pub fn is_min_text_length(value: &str, min_length: usize, unit: Unit) -> Result<()> {
+    if checks::is_min_text_length(value, min_length, unit) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("min_text_len")
+            .with_param("min_len", min_length as u64)
+            .with_param("len", unit.count(value) as u64)
+            .with_param("unit", unit)
+            .into())
+    }
+}
+
+/// Check that text is at most a certain length
+///
+/// ```
+/// # use validatron::validators::{is_max_text_length, Unit};
+/// let x = String::from("año");
+/// assert!(is_max_text_length(&x, 3, Unit::Chars).is_ok());
+/// assert!(is_max_text_length(&x, 3, Unit::Bytes).is_err());
+/// ```
+pub fn is_max_text_length(value: &str, max_length: usize, unit: Unit) -> Result<()> {
+    if checks::is_max_text_length(value, max_length, unit) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("max_text_len")
+            .with_param("max_len", max_length as u64)
+            .with_param("len", unit.count(value) as u64)
+            .with_param("unit", unit)
+            .into())
+    }
+}
+
+/// Check that text has exactly a certain length
+///
+/// ```
+/// # use validatron::validators::{is_exact_text_length, Unit};
+/// assert!(is_exact_text_length("GB", 2, Unit::Chars).is_ok());
+/// assert!(is_exact_text_length("GBR", 2, Unit::Chars).is_err());
+/// ```
+pub fn is_exact_text_length(value: &str, exact_length: usize, unit: Unit) -> Result<()> {
+    if checks::is_exact_text_length(value, exact_length, unit) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("exact_text_len")
+            .with_param("exact_len", exact_length as u64)
+            .with_param("len", unit.count(value) as u64)
+            .with_param("unit", unit)
+            .into())
+    }
+}
+
+/// Check that the length of text is within a range
+///
+/// ```
+/// # use validatron::validators::{is_text_length_in_range, Unit};
+/// assert!(is_text_length_in_range("año", 1..=3, Unit::Chars).is_ok());
+/// assert!(is_text_length_in_range("año", 1..=3, Unit::Bytes).is_err());
+/// ```
+pub fn is_text_length_in_range<R>(value: &str, range: R, unit: Unit) -> Result<()>
+where
+    R: RangeBounds<usize> + Debug,
+{
+    if checks::is_text_length_in_range(value, (range.start_bound(), range.end_bound()), unit) {
+        Ok(())
     } else {
+        Err(Reason::from_code("text_len_range")
+            .with_param("len_range", format!("{:?}", range))
+            .with_param("len", unit.count(value) as u64)
+            .with_param("unit", unit)
+            .into())
+    }
+}
+
+/// Check that a collection or text is not empty
+///
+/// ```
+/// # use validatron::validators::non_empty;
+/// assert!(non_empty(&vec![1]).is_ok());
+/// assert!(non_empty("").is_err());
+/// ```
+pub fn non_empty<C>(value: C) -> Result<()>
+where
+    C: HasLength,
+{
+    if checks::non_empty(value) {
         Ok(())
+    } else {
+        Err(Reason::from_code("non_empty").into())
+    }
+}
+
+/// Check that the items of a collection are unique, see [`Collection`] for the items of
+/// each collection
+///
+/// Every duplicate is reported at its index, with the index of the `first` equal item.
+///
+/// ```
+/// # use validatron::validators::unique;
+/// # use validatron::{Location, Param};
+/// assert!(unique(&vec![1, 2, 3]).is_ok());
+///
+/// let e = unique(&vec![1, 2, 1, 1]).unwrap_err();
+/// let duplicates: Vec<_> = e.flatten().map(|(path, _)| path.to_string()).collect();
+/// assert_eq!(duplicates, ["[2]", "[3]"]);
+/// ```
+pub fn unique<C>(value: &C) -> Result<()>
+where
+    C: Collection + ?Sized,
+    C::Item: Hash + Eq,
+{
+    if checks::unique(value) {
+        return Ok(());
+    }
+
+    let mut first = HashMap::new();
+    let mut eb = crate::Error::build();
+
+    for (i, x) in value.items().enumerate() {
+        match first.entry(x) {
+            Entry::Occupied(e) => {
+                eb.at_index(
+                    i,
+                    Reason::from_code("unique").with_param("first", *e.get() as u64),
+                );
+            }
+            Entry::Vacant(e) => {
+                e.insert(i);
+            }
+        }
+    }
+
+    eb.build()
+}
+
+// reports the first item out of order at its index
+fn sorted_error(code: &'static str, index: Option<usize>) -> Result<()> {
+    match index {
+        Some(i) => crate::Error::build()
+            .at_index(i, Reason::from_code(code))
+            .build(),
+        None => Ok(()),
+    }
+}
+
+/// Check that the items of a collection are in ascending order, allowing equal neighbours
+///
+/// The first item out of order is reported at its index. Items which cannot be compared
+/// with the item before them, such as NaN, are out of order.
+///
+/// ```
+/// # use validatron::validators::sorted;
+/// assert!(sorted(&vec![1, 2, 2, 3]).is_ok());
+/// assert!(sorted(&vec![1, 3, 2]).is_err());
+/// ```
+pub fn sorted<C>(value: &C) -> Result<()>
+where
+    C: Collection + ?Sized,
+    C::Item: PartialOrd,
+{
+    sorted_error(
+        "sorted",
+        checks::first_unsorted(value.items(), |x| x, false),
+    )
+}
+
+/// Check that the items of a collection are in ascending order, without equal neighbours
+///
+/// ```
+/// # use validatron::validators::strictly_sorted;
+/// assert!(strictly_sorted(&vec![1, 2, 3]).is_ok());
+/// assert!(strictly_sorted(&vec![1, 2, 2]).is_err());
+/// ```
+pub fn strictly_sorted<C>(value: &C) -> Result<()>
+where
+    C: Collection + ?Sized,
+    C::Item: PartialOrd,
+{
+    sorted_error(
+        "strictly_sorted",
+        checks::first_unsorted(value.items(), |x| x, true),
+    )
+}
+
+/// Check that the items of a collection are in ascending order of a key, allowing equal
+/// keys
+///
+/// ```
+/// # use validatron::validators::sorted_by_key;
+/// let x = vec!["a", "bb", "cc"];
+/// assert!(sorted_by_key(&x, |x| x.len()).is_ok());
+/// assert!(sorted_by_key(&x, |x| std::cmp::Reverse(x.len())).is_err());
+/// ```
+pub fn sorted_by_key<C, F, K>(value: &C, key: F) -> Result<()>
+where
+    C: Collection + ?Sized,
+    F: FnMut(&C::Item) -> K,
+    K: PartialOrd,
+{
+    sorted_error("sorted", checks::first_unsorted(value.items(), key, false))
+}
+
+/// Check that the items of a collection are in ascending order of a key, without equal
+/// keys
+pub fn strictly_sorted_by_key<C, F, K>(value: &C, key: F) -> Result<()>
+where
+    C: Collection + ?Sized,
+    F: FnMut(&C::Item) -> K,
+    K: PartialOrd,
+{
+    sorted_error(
+        "strictly_sorted",
+        checks::first_unsorted(value.items(), key, true),
+    )
+}
+
+/// Check that a collection contains an item, such as a key of a map
+///
+/// ```
+/// # use validatron::validators::contains;
+/// let x = vec![String::from("GS-1"), String::from("GS-2")];
+/// assert!(contains(&x, "GS-1").is_ok());
+/// assert!(contains(&x, "GS-3").is_err());
+/// ```
+pub fn contains<C, Q>(value: &C, item: Q) -> Result<()>
+where
+    C: Collection + ?Sized,
+    C::Item: PartialEq<Q>,
+    Q: Debug,
+{
+    let param = Param::display(&format_args!("{:?}", item));
+
+    if checks::contains(value, item) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("contains")
+            .with_param("contains", param)
+            .into())
+    }
+}
+
+/// Check that a collection does not contain an item, such as a key of a map
+///
+/// ```
+/// # use validatron::validators::not_contains;
+/// assert!(not_contains(&vec![1, 2], 3).is_ok());
+/// assert!(not_contains(&vec![1, 2], 2).is_err());
+/// ```
+pub fn not_contains<C, Q>(value: &C, item: Q) -> Result<()>
+where
+    C: Collection + ?Sized,
+    C::Item: PartialEq<Q>,
+    Q: Debug,
+{
+    let param = Param::display(&format_args!("{:?}", item));
+
+    if checks::not_contains(value, item) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("not_contains")
+            .with_param("not_contains", param)
+            .into())
+    }
+}
+
+// a value which is not in the format a validator expects
+fn format_error(code: &'static str, value: &str) -> crate::Error {
+    Reason::from_code(code)
+        .with_param("value", value.to_string())
+        .into()
+}
+
+/// Check that text is an email address
+///
+/// Addresses are checked as in RFC 5321, without quoted local parts or comments. The domain
+/// may be a hostname or an address literal such as `[192.0.2.1]`.
+///
+/// ```
+/// # use validatron::validators::email;
+/// assert!(email("first.last@example.com").is_ok());
+/// assert!(email("first.last@").is_err());
+/// ```
+pub fn email<T>(value: &T) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::email(value) {
+        Ok(())
+    } else {
+        Err(format_error("email", value.as_ref()))
+    }
+}
+
+/// Check that text, if present, is an email address
+pub fn option_email<T>(value: &Option<T>) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(Ok(()), email)
+}
+
+/// Check that text is a URL with an authority, such as `https://example.com/path`, using
+/// one of the given schemes
+///
+/// Schemes are compared ignoring case, any scheme is accepted when none are given.
+///
+/// ```
+/// # use validatron::validators::url;
+/// assert!(url("https://example.com/path", &[]).is_ok());
+/// assert!(url("ftp://example.com", &["http", "https"]).is_err());
+/// assert!(url("example.com", &[]).is_err());
+/// ```
+pub fn url<T>(value: &T, schemes: &[&str]) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::url(value, schemes) {
+        return Ok(());
+    }
+
+    let value = value.as_ref();
+
+    // a URL whose scheme is not allowed is told apart from text which is not a URL
+    match formats::url_scheme(value) {
+        Some(_) => Err(Reason::from_code("url_scheme")
+            .with_param("value", value.to_string())
+            .with_param("schemes", schemes.join(", "))
+            .into()),
+        None => Err(format_error("url", value)),
+    }
+}
+
+/// Check that text, if present, is a URL using one of the given schemes
+pub fn option_url<T>(value: &Option<T>, schemes: &[&str]) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(Ok(()), |value| url(value, schemes))
+}
+
+/// Check that text is a hostname as in RFC 1123
+///
+/// ```
+/// # use validatron::validators::hostname;
+/// assert!(hostname("ground-station-1.example.com").is_ok());
+/// assert!(hostname("ground-station-1.example.com.").is_ok());
+/// assert!(hostname("ground_station.example.com").is_err());
+/// ```
+pub fn hostname<T>(value: &T) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::hostname(value) {
+        Ok(())
+    } else {
+        Err(format_error("hostname", value.as_ref()))
+    }
+}
+
+/// Check that text, if present, is a hostname
+pub fn option_hostname<T>(value: &Option<T>) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(Ok(()), hostname)
+}
+
+/// Check that text is an IPv4 or IPv6 address
+///
+/// ```
+/// # use validatron::validators::ip;
+/// assert!(ip("192.0.2.1").is_ok());
+/// assert!(ip("2001:db8::1").is_ok());
+/// assert!(ip("192.0.2").is_err());
+/// ```
+pub fn ip<T>(value: &T) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::ip(value) {
+        Ok(())
+    } else {
+        Err(format_error("ip", value.as_ref()))
+    }
+}
+
+/// Check that text, if present, is an IPv4 or IPv6 address
+pub fn option_ip<T>(value: &Option<T>) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(Ok(()), ip)
+}
+
+/// Check that text is an IPv4 address
+///
+/// ```
+/// # use validatron::validators::ipv4;
+/// assert!(ipv4("192.0.2.1").is_ok());
+/// assert!(ipv4("2001:db8::1").is_err());
+/// ```
+pub fn ipv4<T>(value: &T) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::ipv4(value) {
+        Ok(())
+    } else {
+        Err(format_error("ipv4", value.as_ref()))
+    }
+}
+
+/// Check that text, if present, is an IPv4 address
+pub fn option_ipv4<T>(value: &Option<T>) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(Ok(()), ipv4)
+}
+
+/// Check that text is an IPv6 address
+///
+/// ```
+/// # use validatron::validators::ipv6;
+/// assert!(ipv6("2001:db8::1").is_ok());
+/// assert!(ipv6("192.0.2.1").is_err());
+/// ```
+pub fn ipv6<T>(value: &T) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::ipv6(value) {
+        Ok(())
+    } else {
+        Err(format_error("ipv6", value.as_ref()))
+    }
+}
+
+/// Check that text, if present, is an IPv6 address
+pub fn option_ipv6<T>(value: &Option<T>) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(Ok(()), ipv6)
+}
+
+/// Check that text is an IPv4 or IPv6 network in CIDR notation
+///
+/// The address may have bits set beyond the prefix, as in `192.0.2.1/24`.
+///
+/// ```
+/// # use validatron::validators::cidr;
+/// assert!(cidr("192.0.2.0/24").is_ok());
+/// assert!(cidr("2001:db8::/32").is_ok());
+/// assert!(cidr("192.0.2.0/33").is_err());
+/// ```
+pub fn cidr<T>(value: &T) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::cidr(value) {
+        Ok(())
+    } else {
+        Err(format_error("cidr", value.as_ref()))
+    }
+}
+
+/// Check that text, if present, is a network in CIDR notation
+pub fn option_cidr<T>(value: &Option<T>) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    value.as_ref().map_or(Ok(()), cidr)
+}
+
+/// Check that text matches a regular expression
+///
+/// ```
+/// # use validatron::validators::matches_pattern;
+/// let pattern = regex::Regex::new(r"^[A-Z]{3}-\d+$").unwrap();
+/// assert!(matches_pattern("ABC-123", &pattern).is_ok());
+/// assert!(matches_pattern("abc-123", &pattern).is_err());
+/// ```
+#[cfg(feature = "use-regex")]
+pub fn matches_pattern<T>(value: &T, pattern: &regex::Regex) -> Result<()>
+where
+    T: AsRef<str> + ?Sized,
+{
+    if checks::matches_pattern(value, pattern) {
+        Ok(())
+    } else {
+        Err(Reason::from_code("pattern")
+            .with_param("value", value.as_ref().to_string())
+            .with_param("pattern", pattern.as_str().to_string())
+            .into())
+    }
+}
+
+/// Check that text, if present, matches a regular expression
+///
+/// ```
+/// # use validatron::validators::option_matches_pattern;
+/// let pattern = regex::Regex::new(r"^[A-Z]{3}$").unwrap();
+/// assert!(option_matches_pattern(&Some("ABC"), &pattern).is_ok());
+/// assert!(option_matches_pattern(&None::<String>, &pattern).is_ok());
+/// assert!(option_matches_pattern(&Some("AB"), &pattern).is_err());
+/// ```
+#[cfg(feature = "use-regex")]
+pub fn option_matches_pattern<T>(value: &Option<T>, pattern: &regex::Regex) -> Result<()>
+where
+    T: AsRef<str>,
+{
+    match value {
+        Some(value) => matches_pattern(value, pattern),
+        None => Ok(()),
     }
 }
 
@@ -203,6 +1378,47 @@ mod tests {
         assert!(is_equal(&a, b).is_err());
     }
 
+    #[test]
+    fn codes_and_params() {
+        let e = min(&36, 42).unwrap_err();
+        assert_eq!(
+            e,
+            crate::Error::from(
+                Reason::new("min", "'36' must be greater than or equal to '42'")
+                    .with_param("min", 42u64)
+                    .with_param("value", 36u64)
+            )
+        );
+
+        match is_max_length(vec![1, 2, 3], 2).unwrap_err() {
+            crate::Error::Unstructured(reasons) => {
+                assert_eq!(reasons[0].code(), "max_len");
+                assert_eq!(reasons[0].params()["max_len"], Param::UInt(2));
+                assert_eq!(reasons[0].params()["len"], Param::UInt(3));
+            }
+            crate::Error::Structured(_) => panic!("should not happen"),
+        }
+
+        assert_eq!(
+            gte_field(&1, "end", &2, "start").unwrap_err(),
+            crate::Error::from(
+                Reason::new(
+                    "gte_field",
+                    "end ('1') must be greater than or equal to start ('2')"
+                )
+                .with_param("field", "end")
+                .with_param("other_field", "start")
+                .with_param("other", 2u64)
+                .with_param("value", 1u64)
+            )
+        );
+
+        match is_required::<()>(&None).unwrap_err() {
+            crate::Error::Unstructured(reasons) => assert_eq!(reasons[0].code(), "required"),
+            crate::Error::Structured(_) => panic!("should not happen"),
+        }
+    }
+
     #[test]
     fn test_min() {
         assert!(min(&0, 0).is_ok());
@@ -233,8 +1449,8 @@ mod tests {
         assert!(is_min_length(vec![1, 2, 3], 4).is_err());
         assert!(is_min_length(vec![1, 2], 3).is_err());
 
-        assert!(is_min_length(&[1, 2], 2).is_ok());
-        assert!(is_min_length(&[1, 2, 3, 4, 5], 0).is_ok());
+        assert!(is_min_length([1, 2].as_slice(), 2).is_ok());
+        assert!(is_min_length([1, 2, 3, 4, 5].as_slice(), 0).is_ok());
     }
 
     #[test]
@@ -242,7 +1458,7 @@ mod tests {
         assert!(is_max_length(vec![1, 2, 3], 3).is_ok());
         assert!(is_max_length(vec![1, 2, 3], 2).is_err());
 
-        assert!(is_max_length(&[1, 2], 2).is_ok());
+        assert!(is_max_length([1, 2].as_slice(), 2).is_ok());
 
         assert!(is_max_length(Vec::<i32>::new(), 0).is_ok());
     }
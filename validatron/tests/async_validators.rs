@@ -0,0 +1,377 @@
+use std::collections::{BTreeMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use validatron::{AsyncValidate, Error, Location, Result, Validate};
+
+// a minimal executor, the trait does not depend on any particular runtime
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct Unpark(std::thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+// yields once, as a lookup waiting on I/O would
+#[derive(Default)]
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+// an in-memory stand in for a data store, recording how many lookups were in flight
+// at once
+struct Store {
+    satellites: HashSet<u32>,
+    in_flight: Mutex<(usize, usize)>,
+}
+
+impl Store {
+    async fn exists(&self, id: u32) -> bool {
+        {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            in_flight.0 += 1;
+            in_flight.1 = in_flight.1.max(in_flight.0);
+        }
+
+        YieldNow::default().await;
+
+        self.in_flight.lock().unwrap().0 -= 1;
+        self.satellites.contains(&id)
+    }
+
+    fn max_in_flight(&self) -> usize {
+        self.in_flight.lock().unwrap().1
+    }
+}
+
+thread_local! {
+    static STORE: &'static Store = Box::leak(Box::new(Store {
+        satellites: [1, 2, 3].into_iter().collect(),
+        in_flight: Mutex::new((0, 0)),
+    }));
+}
+
+fn store() -> &'static Store {
+    STORE.with(|x| *x)
+}
+
+async fn satellite_exists(id: &u32) -> Result<()> {
+    if store().exists(*id).await {
+        Ok(())
+    } else {
+        Err(Error::new(format!("satellite {} does not exist", id)))
+    }
+}
+
+fn locations(e: Error) -> Vec<Location> {
+    match e {
+        Error::Structured(map) => map.into_keys().collect(),
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+fn named(names: &[&'static str]) -> Vec<Location> {
+    names.iter().map(|x| Location::Named((*x).into())).collect()
+}
+
+#[derive(Validate)]
+struct Pass {
+    #[validatron(async_function = "satellite_exists")]
+    satellite: u32,
+    #[validatron(async_function = "satellite_exists")]
+    relay: u32,
+    #[validatron(min = 1)]
+    duration: u64,
+}
+
+#[test]
+fn fields_are_checked_concurrently() {
+    let pass = Pass {
+        satellite: 1,
+        relay: 2,
+        duration: 1,
+    };
+
+    assert!(block_on(pass.validate_async()).is_ok());
+    assert_eq!(store().max_in_flight(), 2);
+
+    let pass = Pass {
+        satellite: 4,
+        relay: 2,
+        duration: 0,
+    };
+
+    let e = block_on(pass.validate_async()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "duration: '0' must be greater than or equal to '1'\nsatellite: satellite 4 does not exist"
+    );
+}
+
+#[test]
+fn checks_keep_declaration_order() {
+    async fn first(_: &u32) -> Result<()> {
+        YieldNow::default().await;
+        Err(Error::new("first"))
+    }
+
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(async_function = "first", max = 1)]
+        a: u32,
+    }
+
+    let e = block_on(Foo { a: 2 }.validate_async()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "a: first\na: '2' must be less than or equal to '1'"
+    );
+}
+
+#[test]
+fn nested_and_collections() {
+    #[derive(Validate)]
+    struct Window {
+        #[validatron(min = 1)]
+        minutes: u64,
+    }
+
+    #[derive(Validate)]
+    #[validatron(asynchronous)]
+    struct Schedule {
+        #[validatron]
+        passes: Vec<Pass>,
+        #[validatron]
+        backups: BTreeMap<String, Option<Pass>>,
+        #[validatron]
+        window: Window,
+        #[validatron(each(async_function = "satellite_exists"))]
+        spares: Vec<u32>,
+    }
+
+    let pass = |satellite| Pass {
+        satellite,
+        relay: 1,
+        duration: 1,
+    };
+
+    let schedule = Schedule {
+        passes: vec![pass(1), pass(7)],
+        backups: vec![("a".to_string(), Some(pass(8)))].into_iter().collect(),
+        window: Window { minutes: 0 },
+        spares: vec![3, 9],
+    };
+
+    let e = block_on(schedule.validate_async()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "backups.a[0].satellite: satellite 8 does not exist\n\
+         passes[1].satellite: satellite 7 does not exist\n\
+         spares[1]: satellite 9 does not exist\n\
+         window.minutes: '0' must be greater than or equal to '1'"
+    );
+}
+
+#[test]
+fn enums_and_type_level_functions() {
+    async fn not_retired(x: &Target) -> Result<()> {
+        YieldNow::default().await;
+
+        match x {
+            Target::Satellite(4) => Err(Error::new("satellite is retired")),
+            _ => Ok(()),
+        }
+    }
+
+    #[derive(Validate)]
+    #[validatron(async_function = "not_retired")]
+    enum Target {
+        Satellite(#[validatron(async_function = "satellite_exists")] u32),
+        Station {
+            #[validatron(max_len = 3)]
+            antennas: Vec<u32>,
+        },
+    }
+
+    assert!(block_on(Target::Satellite(1).validate_async()).is_ok());
+    assert_eq!(
+        locations(block_on(Target::Satellite(4).validate_async()).unwrap_err()),
+        vec![Location::Named("not_retired".into()), Location::Index(0)]
+    );
+    assert_eq!(
+        locations(
+            block_on(
+                Target::Station {
+                    antennas: vec![1, 2, 3, 4]
+                }
+                .validate_async()
+            )
+            .unwrap_err()
+        ),
+        named(&["antennas"])
+    );
+}
+
+#[test]
+fn items_entries_and_type_level_checks_are_concurrent() {
+    async fn lead_exists(x: &Fleet) -> Result<()> {
+        satellite_exists(&x.lead).await
+    }
+
+    #[derive(Validate)]
+    #[validatron(async_function = "lead_exists")]
+    struct Fleet {
+        lead: u32,
+        #[validatron(each(async_function = "satellite_exists"))]
+        members: Vec<u32>,
+        #[validatron(values(async_function = "satellite_exists"))]
+        relays: BTreeMap<String, u32>,
+    }
+
+    let fleet = Fleet {
+        lead: 1,
+        members: vec![1, 2, 3],
+        relays: vec![("a".to_string(), 1), ("b".to_string(), 2)]
+            .into_iter()
+            .collect(),
+    };
+
+    assert!(block_on(fleet.validate_async()).is_ok());
+    assert_eq!(store().max_in_flight(), 6);
+
+    let fleet = Fleet {
+        lead: 5,
+        members: vec![1, 6],
+        relays: vec![("a".to_string(), 7)].into_iter().collect(),
+    };
+
+    let e = block_on(fleet.validate_async()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "lead_exists: satellite 5 does not exist\n\
+         members[1]: satellite 6 does not exist\n\
+         relays.a.value: satellite 7 does not exist"
+    );
+}
+
+#[test]
+fn nests_manual_validate_impls() {
+    struct Callsign(String);
+
+    impl Validate for Callsign {
+        fn validate(&self) -> Result<()> {
+            if self.0.is_empty() {
+                Err(Error::new("empty callsign"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[derive(Validate)]
+    struct Crew {
+        #[validatron(async_function = "satellite_exists")]
+        satellite: u32,
+        #[validatron]
+        lead: Callsign,
+        #[validatron]
+        members: Vec<Callsign>,
+    }
+
+    let crew = Crew {
+        satellite: 1,
+        lead: Callsign("apollo".into()),
+        members: vec![Callsign("gemini".into())],
+    };
+    assert!(block_on(crew.validate_async()).is_ok());
+
+    let crew = Crew {
+        satellite: 1,
+        lead: Callsign(String::new()),
+        members: vec![Callsign("gemini".into()), Callsign(String::new())],
+    };
+    assert_eq!(
+        block_on(crew.validate_async()).unwrap_err().to_string(),
+        "lead: empty callsign\nmembers[1]: empty callsign"
+    );
+}
+
+#[test]
+fn nested_async_functions_make_the_type_asynchronous() {
+    #[derive(Validate)]
+    struct Spares {
+        #[validatron(each(async_function = "satellite_exists"))]
+        ids: Vec<u32>,
+    }
+
+    #[derive(Validate)]
+    struct Relays {
+        #[validatron(keys(async_function = "satellite_exists"))]
+        ids: BTreeMap<u32, String>,
+    }
+
+    assert!(block_on(Spares { ids: vec![1, 9] }.validate_async()).is_err());
+
+    let relays = Relays {
+        ids: vec![(9, "a".to_string())].into_iter().collect(),
+    };
+    assert_eq!(
+        block_on(relays.validate_async()).unwrap_err().to_string(),
+        "ids[\"9\"].key: satellite 9 does not exist"
+    );
+}
+
+#[test]
+fn futures_can_move_between_threads() {
+    #[derive(Validate)]
+    #[validatron(asynchronous)]
+    struct Wrapper<T: AsyncValidate> {
+        #[validatron]
+        inner: Vec<T>,
+    }
+
+    let wrapper = Wrapper {
+        inner: vec![Pass {
+            satellite: 9,
+            relay: 1,
+            duration: 1,
+        }],
+    };
+
+    // created on this thread and awaited on another, as a multithreaded runtime would
+    let future = wrapper.validate_async();
+    let result = std::thread::scope(|s| s.spawn(move || block_on(future)).join().unwrap());
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "inner[0].satellite: satellite 9 does not exist"
+    );
+}
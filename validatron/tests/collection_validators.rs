@@ -0,0 +1,194 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use validatron::{Error, Reason, Validate};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Band {
+    S,
+    X,
+    Ka,
+}
+
+#[derive(Clone)]
+struct Pass {
+    start: u64,
+}
+
+impl Pass {
+    fn start(&self) -> u64 {
+        self.start
+    }
+}
+
+#[derive(Clone, Validate)]
+struct Schedule {
+    #[validatron(non_empty)]
+    name: String,
+    #[validatron(unique, non_empty)]
+    satellites: Vec<u32>,
+    #[validatron(sorted)]
+    timestamps: VecDeque<f64>,
+    #[validatron(strictly_sorted)]
+    revisions: [u8; 3],
+    #[validatron(sorted_by_key = "Pass::start")]
+    passes: Vec<Pass>,
+    #[validatron(contains = "\"GS-1\"")]
+    stations: HashSet<String>,
+    #[validatron(contains = "Band::S", not_contains = "Band::Ka")]
+    bands: Vec<Band>,
+    #[validatron(not_contains = 0)]
+    priorities: BTreeMap<u8, String>,
+    #[validatron(unique)]
+    tags: BTreeSet<&'static str>,
+}
+
+fn schedule() -> Schedule {
+    Schedule {
+        name: "daily".to_string(),
+        satellites: vec![101, 102, 103],
+        timestamps: VecDeque::from([0.0, 1.5, 1.5, 2.0]),
+        revisions: [1, 2, 3],
+        passes: vec![Pass { start: 10 }, Pass { start: 20 }],
+        stations: HashSet::from(["GS-1".to_string(), "GS-2".to_string()]),
+        bands: vec![Band::S, Band::X],
+        priorities: BTreeMap::from([(1, "high".to_string())]),
+        tags: BTreeSet::from(["ais"]),
+    }
+}
+
+fn check(x: &Schedule) -> Result<(), Error> {
+    assert_eq!(x.is_valid(), x.validate().is_ok());
+    x.validate()
+}
+
+#[test]
+fn valid_collections() {
+    assert!(check(&schedule()).is_ok());
+
+    let x = Schedule {
+        timestamps: VecDeque::new(),
+        passes: vec![Pass { start: 10 }, Pass { start: 10 }],
+        ..schedule()
+    };
+    assert!(check(&x).is_ok());
+}
+
+#[test]
+fn invalid_collections() {
+    type Mutation = fn(&mut Schedule);
+
+    let mutations: &[(&str, Mutation)] = &[
+        ("name", |x| x.name.clear()),
+        ("satellites", |x| x.satellites.clear()),
+        ("satellites[3]", |x| x.satellites.push(101)),
+        ("timestamps[2]", |x| x.timestamps[2] = 1.0),
+        ("timestamps[1]", |x| x.timestamps[1] = f64::NAN),
+        ("revisions[2]", |x| x.revisions[2] = 2),
+        ("passes[1]", |x| x.passes[1].start = 5),
+        ("stations", |x| {
+            x.stations.remove("GS-1");
+        }),
+        ("bands", |x| x.bands.retain(|x| *x != Band::S)),
+        ("bands", |x| x.bands.push(Band::Ka)),
+        ("priorities", |x| {
+            x.priorities.insert(0, "none".to_string());
+        }),
+    ];
+
+    for (path, mutate) in mutations {
+        let mut x = schedule();
+        mutate(&mut x);
+
+        let e = check(&x).unwrap_err();
+        let paths: Vec<_> = e.flatten().map(|(path, _)| path.to_string()).collect();
+        assert_eq!(paths, [*path], "{}", e);
+    }
+}
+
+#[test]
+fn every_duplicate_is_reported() {
+    let x = Schedule {
+        satellites: vec![7, 8, 7, 9, 8, 7],
+        ..schedule()
+    };
+
+    assert_eq!(
+        check(&x).unwrap_err(),
+        Error::build()
+            .try_at_named(
+                "satellites",
+                Error::build()
+                    .at_index(2, Reason::from_code("unique").with_param("first", 0u64))
+                    .at_index(4, Reason::from_code("unique").with_param("first", 1u64))
+                    .at_index(5, Reason::from_code("unique").with_param("first", 0u64))
+                    .build()
+            )
+            .build()
+            .unwrap_err()
+    );
+    assert_eq!(
+        x.validate().unwrap_err().to_string().lines().next(),
+        Some("satellites[2]: item is a duplicate of the item at index 0")
+    );
+}
+
+#[test]
+fn large_collections() {
+    let mut x = Schedule {
+        satellites: (0..100).collect(),
+        ..schedule()
+    };
+    assert!(check(&x).is_ok());
+
+    x.satellites.push(42);
+    assert!(check(&x).is_err());
+}
+
+#[test]
+fn only_the_first_unsorted_item_is_reported() {
+    let x = Schedule {
+        timestamps: VecDeque::from([3.0, 2.0, 1.0]),
+        revisions: [3, 2, 1],
+        ..schedule()
+    };
+
+    assert_eq!(
+        check(&x).unwrap_err().to_string(),
+        "revisions[1]: item is out of order, it must be greater than the item before it\n\
+         timestamps[1]: item is out of order, it must not be less than the item before it"
+    );
+}
+
+#[test]
+fn contains_messages() {
+    let mut x = schedule();
+    x.stations.clear();
+    x.bands.push(Band::Ka);
+
+    assert_eq!(
+        check(&x).unwrap_err().to_string(),
+        "bands: collection must not contain Ka\n\
+         stations: collection must contain \"GS-1\""
+    );
+}
+
+#[test]
+fn custom_messages() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(contains = 42, message = "{contains} is required")]
+        a: Option<u32>,
+        #[validatron(unique, message = "ids must be unique")]
+        b: Vec<u32>,
+    }
+
+    let e = Foo {
+        a: None,
+        b: vec![1, 1],
+    }
+    .validate()
+    .unwrap_err();
+
+    // the message is given to each duplicate, at its index
+    assert_eq!(e.to_string(), "a: 42 is required\nb[1]: ids must be unique");
+}
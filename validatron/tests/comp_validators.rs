@@ -8,9 +8,9 @@ fn field_min_validator() {
         a: u64,
     }
 
-    assert_eq!(Foo { a: 10 }.validate().is_ok(), true);
-    assert_eq!(Foo { a: 20 }.validate().is_ok(), true);
-    assert_eq!(Foo { a: 0 }.validate().is_ok(), false);
+    assert!(Foo { a: 10 }.validate().is_ok());
+    assert!(Foo { a: 20 }.validate().is_ok());
+    assert!(Foo { a: 0 }.validate().is_err());
 }
 
 #[test]
@@ -21,10 +21,10 @@ fn field_option_min_validator() {
         a: Option<u64>,
     }
 
-    assert_eq!(Foo { a: None }.validate().is_ok(), true);
-    assert_eq!(Foo { a: Some(10) }.validate().is_ok(), true);
-    assert_eq!(Foo { a: Some(20) }.validate().is_ok(), true);
-    assert_eq!(Foo { a: Some(0) }.validate().is_ok(), false);
+    assert!(Foo { a: None }.validate().is_ok());
+    assert!(Foo { a: Some(10) }.validate().is_ok());
+    assert!(Foo { a: Some(20) }.validate().is_ok());
+    assert!(Foo { a: Some(0) }.validate().is_err());
 }
 
 #[test]
@@ -35,9 +35,9 @@ fn field_max_validator() {
         a: u64,
     }
 
-    assert_eq!(Foo { a: 10 }.validate().is_ok(), true);
-    assert_eq!(Foo { a: 20 }.validate().is_ok(), false);
-    assert_eq!(Foo { a: 0 }.validate().is_ok(), true);
+    assert!(Foo { a: 10 }.validate().is_ok());
+    assert!(Foo { a: 20 }.validate().is_err());
+    assert!(Foo { a: 0 }.validate().is_ok());
 }
 
 #[test]
@@ -47,10 +47,10 @@ fn field_option_max_validator() {
         #[validatron(option_max = 10)]
         a: Option<u64>,
     }
-    assert_eq!(Foo { a: None }.validate().is_ok(), true);
-    assert_eq!(Foo { a: Some(10) }.validate().is_ok(), true);
-    assert_eq!(Foo { a: Some(20) }.validate().is_ok(), false);
-    assert_eq!(Foo { a: Some(0) }.validate().is_ok(), true);
+    assert!(Foo { a: None }.validate().is_ok());
+    assert!(Foo { a: Some(10) }.validate().is_ok());
+    assert!(Foo { a: Some(20) }.validate().is_err());
+    assert!(Foo { a: Some(0) }.validate().is_ok());
 }
 
 #[test]
@@ -66,22 +66,109 @@ fn field_equal_validator() {
         b: String,
     }
 
+    assert!(Foo {
+        a: 10,
+        b: "hello world!".into()
+    }
+    .validate()
+    .is_ok());
+    assert!(Foo {
+        a: 20,
+        b: "".into()
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn field_exclusive_validators() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(exclusive_min = 0.0, exclusive_max = 1.0)]
+        a: f64,
+        #[validatron(option_exclusive_min = 0, option_exclusive_max = 10)]
+        b: Option<i64>,
+    }
+
+    let valid = |a, b| {
+        let x = Foo { a, b };
+        assert_eq!(x.is_valid(), x.validate().is_ok());
+        x.is_valid()
+    };
+
+    assert!(valid(0.5, None));
+    assert!(valid(0.5, Some(9)));
+    assert!(!valid(0.0, None));
+    assert!(!valid(1.0, None));
+    assert!(!valid(0.5, Some(0)));
+    assert!(!valid(0.5, Some(10)));
+}
+
+#[test]
+fn field_range_validator() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(range(min = 0, max_exclusive = 360))]
+        heading: i64,
+        #[validatron(range(min_exclusive = 0.0, max = 1.0))]
+        ratio: f64,
+        #[validatron(range(max = "self.heading"))]
+        offset: i64,
+        #[validatron(option_range(min = 'a', max = 'z'))]
+        grade: Option<char>,
+    }
+
+    let foo = |heading, ratio, grade| Foo {
+        heading,
+        ratio,
+        offset: 0,
+        grade,
+    };
+
+    for (x, valid) in [
+        (foo(0, 1.0, None), true),
+        (foo(359, 0.1, Some('a')), true),
+        (foo(-1, 0.5, None), false),
+        (foo(360, 0.5, None), false),
+        (foo(10, 0.0, None), false),
+        (foo(10, 1.5, None), false),
+        (foo(10, 0.5, Some('A')), false),
+    ] {
+        assert_eq!(x.validate().is_ok(), valid);
+        assert_eq!(x.is_valid(), valid);
+    }
+
     assert_eq!(
         Foo {
-            a: 10,
-            b: "hello world!".into()
+            offset: 361,
+            ..foo(360, 0.0, Some('~'))
         }
         .validate()
-        .is_ok(),
-        true
+        .unwrap_err()
+        .to_string(),
+        [
+            "grade: '~' must be in [a, z]",
+            "heading: '360' must be in [0, 360)",
+            "offset: '361' must be in (-∞, 360]",
+            "ratio: '0' must be in (0, 1]",
+        ]
+        .join("\n")
     );
+}
+
+#[test]
+fn field_range_message() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(
+            range(min = 0, max_exclusive = 360),
+            message = "heading {value} must be at least {min} and below {max_exclusive}"
+        )]
+        heading: i64,
+    }
+
     assert_eq!(
-        Foo {
-            a: 20,
-            b: "".into()
-        }
-        .validate()
-        .is_ok(),
-        false
+        Foo { heading: 400 }.validate().unwrap_err().to_string(),
+        "heading: heading 400 must be at least 0 and below 360"
     );
 }
@@ -0,0 +1,191 @@
+use validatron::{Error, Location, Reason, Validate};
+
+#[derive(Debug, PartialEq)]
+enum Source {
+    Manual,
+    Catalog,
+}
+
+fn locations(e: Error) -> Vec<Location> {
+    match e {
+        Error::Structured(map) => map.into_keys().collect(),
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+#[test]
+fn required_if() {
+    #[derive(Validate)]
+    struct Orbit {
+        source: Source,
+        #[validatron(required_if = "self.source == Source::Manual")]
+        tle: Option<String>,
+    }
+
+    assert!(Orbit {
+        source: Source::Catalog,
+        tle: None
+    }
+    .validate()
+    .is_ok());
+    assert!(Orbit {
+        source: Source::Manual,
+        tle: Some("1 25544U".into())
+    }
+    .validate()
+    .is_ok());
+
+    let e = Orbit {
+        source: Source::Manual,
+        tle: None,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        e,
+        Error::Structured(
+            vec![(
+                Location::Named("tle".into()),
+                Reason::new("required", "Option is required to have a value").into()
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn required_unless() {
+    #[derive(Validate)]
+    struct Orbit {
+        catalog_id: Option<u64>,
+        #[validatron(required_unless = "self.catalog_id.is_some()")]
+        tle: Option<String>,
+    }
+
+    assert!(Orbit {
+        catalog_id: Some(25544),
+        tle: None
+    }
+    .validate()
+    .is_ok());
+    assert!(Orbit {
+        catalog_id: None,
+        tle: None
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn when_guard() {
+    #[derive(Validate)]
+    struct Pass {
+        scheduled: bool,
+        #[validatron(when = "self.scheduled", required, option_min = 10)]
+        duration: Option<u64>,
+        #[validatron(min = 1)]
+        priority: u64,
+    }
+
+    assert!(Pass {
+        scheduled: false,
+        duration: Some(1),
+        priority: 1
+    }
+    .validate()
+    .is_ok());
+
+    let e = Pass {
+        scheduled: true,
+        duration: Some(1),
+        priority: 0,
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        locations(e),
+        vec![
+            Location::Named("duration".into()),
+            Location::Named("priority".into())
+        ]
+    );
+
+    assert!(Pass {
+        scheduled: true,
+        duration: None,
+        priority: 1
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn when_is_scoped_to_attribute() {
+    #[derive(Validate)]
+    struct Foo {
+        strict: bool,
+        #[validatron(when = "self.strict", max = 10, message = "at most {max} when strict")]
+        #[validatron(max = 100)]
+        a: u64,
+    }
+
+    assert!(Foo {
+        strict: false,
+        a: 50
+    }
+    .validate()
+    .is_ok());
+    assert!(Foo {
+        strict: false,
+        a: 101
+    }
+    .validate()
+    .is_err());
+
+    let e = Foo {
+        strict: true,
+        a: 50,
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(e.to_string(), "a: at most 10 when strict");
+}
+
+#[test]
+fn enum_variants() {
+    #[derive(Validate)]
+    enum Request {
+        Orbit {
+            source: Source,
+            #[validatron(required_if = "self.source == Source::Manual")]
+            tle: Option<String>,
+        },
+        Limits(
+            bool,
+            #[validatron(when = "self.0", each(max = 10))] Vec<u64>,
+            #[validatron(required_unless = "!self.0")] Option<u64>,
+        ),
+    }
+
+    assert!(Request::Orbit {
+        source: Source::Catalog,
+        tle: None
+    }
+    .validate()
+    .is_ok());
+    assert!(Request::Orbit {
+        source: Source::Manual,
+        tle: None
+    }
+    .validate()
+    .is_err());
+
+    assert!(Request::Limits(false, vec![11], None).validate().is_ok());
+
+    let e = Request::Limits(true, vec![11], None)
+        .validate()
+        .unwrap_err();
+    assert_eq!(locations(e), vec![Location::Index(1), Location::Index(2)]);
+}
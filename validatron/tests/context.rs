@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use validatron::{Error, Location, Result, Validate, ValidateWith};
+
+struct Limits {
+    stations: Vec<u32>,
+    max_passes: u64,
+}
+
+fn limits() -> Limits {
+    Limits {
+        stations: vec![1, 2, 3],
+        max_passes: 5,
+    }
+}
+
+fn known_station(id: &u32, ctx: &Limits) -> Result<()> {
+    if ctx.stations.contains(id) {
+        Ok(())
+    } else {
+        Err(Error::new(format!("unknown station {}", id)))
+    }
+}
+
+fn locations(e: Error) -> Vec<Location> {
+    match e {
+        Error::Structured(map) => map.into_keys().collect(),
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+fn named(names: &[&'static str]) -> Vec<Location> {
+    names.iter().map(|x| Location::Named((*x).into())).collect()
+}
+
+#[derive(Validate)]
+struct Window {
+    #[validatron(min = 1)]
+    minutes: u64,
+}
+
+#[derive(Validate)]
+#[validatron(context = "Limits")]
+struct Contact {
+    #[validatron(context_function = "known_station")]
+    station: u32,
+    #[validatron(max = "ctx.max_passes")]
+    passes: u64,
+    #[validatron]
+    window: Window,
+}
+
+#[test]
+fn struct_context() {
+    let ctx = limits();
+
+    let contact = Contact {
+        station: 1,
+        passes: 5,
+        window: Window { minutes: 1 },
+    };
+    assert!(contact.validate_with(&ctx).is_ok());
+
+    let contact = Contact {
+        station: 4,
+        passes: 6,
+        window: Window { minutes: 0 },
+    };
+    assert_eq!(
+        locations(contact.validate_with(&ctx).unwrap_err()),
+        named(&["passes", "station", "window"])
+    );
+}
+
+#[test]
+fn propagates_into_collections() {
+    #[derive(Validate)]
+    #[validatron(context = "Limits")]
+    struct Schedule {
+        #[validatron]
+        contacts: Vec<Contact>,
+        #[validatron]
+        backups: HashMap<String, Option<Contact>>,
+        #[validatron]
+        windows: Vec<Window>,
+    }
+
+    let contact = |station| Contact {
+        station,
+        passes: 1,
+        window: Window { minutes: 1 },
+    };
+
+    let schedule = Schedule {
+        contacts: vec![contact(1), contact(9)],
+        backups: vec![("a".to_string(), Some(contact(8)))]
+            .into_iter()
+            .collect(),
+        windows: vec![Window { minutes: 0 }],
+    };
+
+    let e = schedule.validate_with(&limits()).unwrap_err();
+
+    assert_eq!(
+        e.to_string(),
+        "backups.a[0].station: unknown station 8\n\
+         contacts[1].station: unknown station 9\n\
+         windows[0].minutes: '0' must be greater than or equal to '1'"
+    );
+}
+
+#[test]
+fn enum_and_type_level_context() {
+    fn within_limits(x: &Pass, ctx: &Limits) -> Result<()> {
+        match x {
+            Pass::Single(n) if *n > ctx.max_passes => Err(Error::new("too many passes")),
+            _ => Ok(()),
+        }
+    }
+
+    #[derive(Validate)]
+    #[validatron(context = "Limits", context_function = "within_limits")]
+    enum Pass {
+        Single(u64),
+        Stations {
+            #[validatron(each(context_function = "known_station"))]
+            ids: Vec<u32>,
+            #[validatron(when = "ctx.max_passes > 1", min_len = 1)]
+            names: Vec<String>,
+        },
+    }
+
+    let ctx = limits();
+
+    assert!(Pass::Single(5).validate_with(&ctx).is_ok());
+    assert_eq!(
+        locations(Pass::Single(6).validate_with(&ctx).unwrap_err()),
+        named(&["within_limits"])
+    );
+
+    let e = Pass::Stations {
+        ids: vec![1, 7],
+        names: vec![],
+    }
+    .validate_with(&ctx)
+    .unwrap_err();
+    assert_eq!(locations(e), named(&["ids", "names"]));
+}
+
+#[test]
+fn nests_manual_validate_impls() {
+    struct Callsign(String);
+
+    impl Validate for Callsign {
+        fn validate(&self) -> Result<()> {
+            if self.0.is_empty() {
+                Err(Error::new("empty callsign"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl<C: ?Sized> ValidateWith<C> for Callsign {
+        fn validate_with(&self, _: &C) -> Result<()> {
+            self.validate()
+        }
+    }
+
+    #[derive(Validate)]
+    #[validatron(context = "Limits")]
+    struct Crew {
+        #[validatron]
+        lead: Callsign,
+        #[validatron]
+        members: Vec<Callsign>,
+    }
+
+    let crew = Crew {
+        lead: Callsign("apollo".into()),
+        members: vec![Callsign("gemini".into())],
+    };
+    assert!(crew.validate_with(&limits()).is_ok());
+
+    let crew = Crew {
+        lead: Callsign(String::new()),
+        members: vec![Callsign("gemini".into()), Callsign(String::new())],
+    };
+    assert_eq!(
+        crew.validate_with(&limits()).unwrap_err().to_string(),
+        "lead: empty callsign\nmembers[1]: empty callsign"
+    );
+}
+
+#[test]
+fn plain_types_accept_any_context() {
+    #[derive(Validate)]
+    struct Wrapper<'a, T: Validate> {
+        #[validatron]
+        inner: &'a T,
+    }
+
+    let window = Window { minutes: 0 };
+    let wrapper = Wrapper { inner: &window };
+
+    assert!(wrapper.validate().is_err());
+    assert!(wrapper.validate_with(&limits()).is_err());
+    assert!(wrapper.validate_with("any context").is_err());
+
+    let windows = vec![Window { minutes: 1 }, Window { minutes: 0 }];
+    assert_eq!(
+        windows.validate_with(&limits()).unwrap_err().to_string(),
+        "[1].minutes: '0' must be greater than or equal to '1'"
+    );
+    assert!(vec![Window { minutes: 1 }].validate_with(&()).is_ok());
+}
+
+#[test]
+fn generic_fields_receive_the_context() {
+    #[derive(Validate)]
+    #[validatron(context = "Limits")]
+    struct Slot<T: ValidateWith<Limits>> {
+        #[validatron]
+        inner: T,
+    }
+
+    let ctx = limits();
+
+    let slot = Slot {
+        inner: Contact {
+            station: 9,
+            passes: 1,
+            window: Window { minutes: 1 },
+        },
+    };
+    assert_eq!(
+        slot.validate_with(&ctx).unwrap_err().to_string(),
+        "inner.station: unknown station 9"
+    );
+
+    let slot = Slot {
+        inner: Window { minutes: 0 },
+    };
+    assert_eq!(
+        slot.validate_with(&ctx).unwrap_err().to_string(),
+        "inner.minutes: '0' must be greater than or equal to '1'"
+    );
+}
@@ -0,0 +1,203 @@
+use validatron::{Error, Location, Reason, Validate};
+
+fn single(field: &str, e: Error) -> Error {
+    match e {
+        Error::Structured(mut map) => map
+            .remove(&Location::Named(field.to_string().into()))
+            .unwrap(),
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+#[test]
+fn struct_field_comparison() {
+    #[derive(Validate)]
+    struct Window {
+        start_time: u64,
+        #[validatron(gte_field = "start_time")]
+        end_time: u64,
+    }
+
+    assert!(Window {
+        start_time: 1,
+        end_time: 1
+    }
+    .validate()
+    .is_ok());
+
+    let e = Window {
+        start_time: 10,
+        end_time: 5,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        single("end_time", e),
+        Reason::new(
+            "gte_field",
+            "end_time ('5') must be greater than or equal to start_time ('10')"
+        )
+        .with_param("field", "end_time")
+        .with_param("other_field", "start_time")
+        .with_param("other", 10u64)
+        .with_param("value", 5u64)
+        .into()
+    );
+}
+
+#[test]
+fn struct_self_expression() {
+    #[derive(Validate)]
+    struct Window {
+        start_time: u64,
+        #[validatron(min = "self.start_time", max = "self.start_time + 100")]
+        end_time: u64,
+    }
+
+    assert!(Window {
+        start_time: 10,
+        end_time: 110
+    }
+    .validate()
+    .is_ok());
+    assert!(Window {
+        start_time: 10,
+        end_time: 9
+    }
+    .validate()
+    .is_err());
+    assert!(Window {
+        start_time: 10,
+        end_time: 111
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn enum_variants() {
+    fn positive(x: &i64) -> bool {
+        *x > 0
+    }
+
+    #[derive(Validate)]
+    enum Range {
+        Named {
+            low: i64,
+            #[validatron(gt_field = "low", max = "self.low + 10")]
+            high: i64,
+        },
+        Tuple(
+            #[validatron(predicate = "positive")] i64,
+            #[validatron(lte_field = "0", min = "self.0 - 5")] i64,
+        ),
+    }
+
+    assert!(Range::Named { low: 1, high: 2 }.validate().is_ok());
+    assert!(Range::Named { low: 1, high: 12 }.validate().is_err());
+
+    let e = Range::Named { low: 1, high: 1 }.validate().unwrap_err();
+    match single("high", e) {
+        Error::Unstructured(reasons) => {
+            assert_eq!(reasons[0].code(), "gt_field");
+            assert_eq!(
+                reasons[0].message(),
+                "high ('1') must be greater than low ('1')"
+            );
+        }
+        Error::Structured(_) => panic!("should not happen"),
+    }
+
+    assert!(Range::Tuple(5, 5).validate().is_ok());
+    assert!(Range::Tuple(5, -1).validate().is_err());
+
+    let e = Range::Tuple(5, 6).validate().unwrap_err();
+    assert_eq!(
+        e,
+        Error::Structured(
+            vec![(
+                Location::Index(1),
+                Reason::new("lte_field", "1 ('6') must be less than or equal to 0 ('5')")
+                    .with_param("field", "1")
+                    .with_param("other_field", "0")
+                    .with_param("other", 5u64)
+                    .with_param("value", 6u64)
+                    .into()
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn non_copy_fields() {
+    #[derive(Validate)]
+    struct Signup {
+        password: String,
+        #[validatron(eq_field = "password", message = "{value} does not match {eq_field}")]
+        confirmation: String,
+    }
+
+    #[derive(Validate)]
+    enum Change {
+        Password {
+            password: String,
+            #[validatron(eq_field = "password")]
+            confirmation: String,
+        },
+    }
+
+    let e = Signup {
+        password: "a".into(),
+        confirmation: "b".into(),
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        single("confirmation", e),
+        Reason::new("eq_field", "b does not match a")
+            .with_param("field", "confirmation")
+            .with_param("other_field", "password")
+            .with_param("other", "a")
+            .with_param("value", "b")
+            .into()
+    );
+
+    assert!(Change::Password {
+        password: "a".into(),
+        confirmation: "a".into()
+    }
+    .validate()
+    .is_ok());
+}
+
+#[cfg(feature = "use-serde")]
+#[test]
+fn serde_names_in_messages() {
+    #[derive(serde::Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    struct Window {
+        start_time: u64,
+        #[validatron(lt_field = "start_time")]
+        end_time: u64,
+    }
+
+    let e = Window {
+        start_time: 1,
+        end_time: 2,
+    }
+    .validate()
+    .unwrap_err();
+
+    match single("endTime", e) {
+        Error::Unstructured(reasons) => {
+            assert_eq!(
+                reasons[0].message(),
+                "endTime ('2') must be less than startTime ('1')"
+            )
+        }
+        Error::Structured(_) => panic!("should not happen"),
+    }
+}
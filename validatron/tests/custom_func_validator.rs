@@ -1,4 +1,4 @@
-use validatron::{Error, Location, Validate};
+use validatron::{Error, Location, Reason, Validate};
 
 #[test]
 fn test_custom_field_validator() {
@@ -89,7 +89,9 @@ fn uses_existing_function() {
         Error::Structured(
             vec![(
                 Location::Index(0),
-                Error::Unstructured(vec!["Predicate \"is_some\" failed".into()])
+                Reason::new("predicate", "Predicate \"is_some\" failed")
+                    .with_param("predicate", "is_some")
+                    .into()
             )]
             .into_iter()
             .collect()
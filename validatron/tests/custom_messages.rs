@@ -0,0 +1,219 @@
+use validatron::{Error, Location, Reason, Result, Validate};
+
+fn single(field: &str, e: Error) -> Error {
+    match e {
+        Error::Structured(mut map) => map
+            .remove(&Location::Named(field.to_string().into()))
+            .unwrap(),
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+fn message(e: &Error) -> String {
+    match e {
+        Error::Unstructured(reasons) => reasons[0].message().into_owned(),
+        Error::Structured(_) => panic!("should not happen"),
+    }
+}
+
+#[test]
+fn message_with_params() {
+    #[derive(Validate)]
+    struct Orbit {
+        #[validatron(min = 42, message = "altitude must be at least {min} km")]
+        altitude: u64,
+    }
+
+    assert!(Orbit { altitude: 42 }.validate().is_ok());
+
+    let e = Orbit { altitude: 36 }.validate().unwrap_err();
+    assert_eq!(
+        single("altitude", e),
+        Reason::new("min", "altitude must be at least 42 km")
+            .with_param("min", 42u64)
+            .with_param("value", 36u64)
+            .into()
+    );
+}
+
+#[test]
+fn message_with_value_and_expressions() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(
+            min = "40. + 2.",
+            max = 100.,
+            message = "{value} is not between {min} and {max:.1}"
+        )]
+        a: f64,
+    }
+
+    let e = single("a", Foo { a: 101. }.validate().unwrap_err());
+    assert_eq!(message(&e), "101 is not between 42 and 100.0");
+
+    let e = single("a", Foo { a: 1. }.validate().unwrap_err());
+    assert_eq!(message(&e), "1 is not between 42 and 100.0");
+}
+
+#[test]
+fn message_is_scoped_to_attribute() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(min = 10, message = "too small")]
+        #[validatron(max = 20)]
+        a: u64,
+    }
+
+    let e = single("a", Foo { a: 1 }.validate().unwrap_err());
+    assert_eq!(message(&e), "too small");
+
+    let e = single("a", Foo { a: 21 }.validate().unwrap_err());
+    assert_eq!(message(&e), "'21' must be less than or equal to '20'");
+}
+
+#[test]
+fn message_escaped_braces() {
+    #[derive(Validate)]
+    struct Foo(#[validatron(required, message = "{{required}}")] Option<u8>);
+
+    let e = Foo(None).validate().unwrap_err();
+    assert_eq!(
+        e,
+        Error::Structured(
+            vec![(
+                Location::Index(0),
+                Reason::new("required", "{required}").into()
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn message_in_each() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(each(min = 14, message = "{value} is below {min}"))]
+        a: Vec<u64>,
+    }
+
+    let e = Foo { a: vec![14, 2] }.validate().unwrap_err();
+    assert_eq!(
+        single("a", e),
+        Error::Structured(
+            vec![(
+                Location::Index(1),
+                Reason::new("min", "2 is below 14")
+                    .with_param("min", 14u64)
+                    .with_param("value", 2u64)
+                    .into()
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn message_on_custom_functions() {
+    fn is_even(x: &u32) -> Result<()> {
+        if x % 2 == 0 {
+            Ok(())
+        } else {
+            Err(Error::new("odd"))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validatron(function = "check", message = "foo is invalid")]
+    enum Foo {
+        A(#[validatron(function = "is_even", message = "{value} must be even")] u32),
+        B,
+    }
+
+    fn check(x: &Foo) -> Result<()> {
+        match x {
+            Foo::A(_) => Ok(()),
+            Foo::B => Err(Error::new("is B")),
+        }
+    }
+
+    let e = Foo::A(3).validate().unwrap_err();
+    assert_eq!(
+        e,
+        Error::Structured(
+            vec![(Location::Index(0), Error::new("3 must be even"))]
+                .into_iter()
+                .collect()
+        )
+    );
+
+    let e = Foo::B.validate().unwrap_err();
+    assert_eq!(single("check", e), Error::new("foo is invalid"));
+}
+
+#[test]
+fn message_survives_localize() {
+    use validatron::catalog::TemplateCatalog;
+
+    #[derive(Validate)]
+    struct Orbit {
+        #[validatron(min = 42, message = "altitude must be at least {min} km")]
+        altitude: u64,
+        #[validatron(min = 42)]
+        inclination: u64,
+    }
+
+    let catalog = TemplateCatalog::new().with_template("de", "min", "muss mindestens {min} sein");
+
+    let e = Orbit {
+        altitude: 36,
+        inclination: 0,
+    }
+    .validate()
+    .unwrap_err()
+    .localize(&catalog, "de");
+
+    assert_eq!(
+        e.to_string(),
+        "altitude: altitude must be at least 42 km\ninclination: muss mindestens 42 sein"
+    );
+}
+
+#[test]
+fn message_applies_to_every_reason() {
+    use validatron::validators::{min, multiple_of};
+
+    fn checks(x: &u64) -> Result<()> {
+        let mut e = min(x, 10).err();
+        if let Err(other) = multiple_of(x, 4) {
+            e.get_or_insert_with(|| Error::Unstructured(vec![]))
+                .merge(other);
+        }
+        e.map_or(Ok(()), Err)
+    }
+
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(function = "checks", message = "{value} is not a valid size")]
+        size: u64,
+    }
+
+    let e = single("size", Foo { size: 3 }.validate().unwrap_err());
+    let codes: Vec<_> = e
+        .flatten()
+        .map(|(_, r)| (r.code().to_string(), r.message().into_owned()))
+        .collect();
+
+    assert_eq!(
+        codes,
+        vec![
+            ("min".to_string(), "3 is not a valid size".to_string()),
+            (
+                "multiple_of".to_string(),
+                "3 is not a valid size".to_string()
+            ),
+        ]
+    );
+}
@@ -0,0 +1,172 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use validatron::{validators, Error, Location, Validate};
+
+#[test]
+fn each_vec() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(each(min = 14))]
+        a: Vec<u64>,
+    }
+
+    assert!(Foo { a: vec![] }.validate().is_ok());
+    assert!(Foo { a: vec![14, 15] }.validate().is_ok());
+
+    let e = Foo {
+        a: vec![14, 2, 15, 3],
+    }
+    .validate()
+    .unwrap_err();
+
+    match e {
+        Error::Structured(map) => {
+            assert_eq!(map.len(), 1);
+
+            match &map[&Location::Named("a".into())] {
+                Error::Structured(items) => {
+                    assert_eq!(items.len(), 2);
+                    assert!(items.contains_key(&Location::Index(1)));
+                    assert!(items.contains_key(&Location::Index(3)));
+                }
+                Error::Unstructured(_) => panic!("should not happen"),
+            }
+        }
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+#[test]
+fn each_multiple_validators() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(each(min = 1, max = 10), max_len = 3)]
+        a: VecDeque<i32>,
+    }
+
+    assert!(Foo {
+        a: vec![1, 10].into()
+    }
+    .validate()
+    .is_ok());
+    assert!(Foo {
+        a: vec![0, 11].into()
+    }
+    .validate()
+    .is_err());
+    assert!(Foo {
+        a: vec![1, 1, 1, 1].into()
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn each_option() {
+    #[derive(Validate)]
+    struct Foo(#[validatron(each(equal = 42))] Option<i32>);
+
+    assert!(Foo(None).validate().is_ok());
+    assert!(Foo(Some(42)).validate().is_ok());
+
+    let e = Foo(Some(1)).validate().unwrap_err();
+    assert_eq!(
+        e,
+        Error::Structured(
+            vec![(
+                Location::Index(0),
+                Error::Structured(
+                    vec![(
+                        Location::Index(0),
+                        validators::is_equal(&1, 42).unwrap_err()
+                    )]
+                    .into_iter()
+                    .collect()
+                )
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn each_slices_arrays_and_sets() {
+    #[derive(Validate)]
+    struct Foo<'a> {
+        #[validatron(each(max = 5))]
+        a: &'a [u8],
+        #[validatron(each(max = 5))]
+        b: [u8; 3],
+        #[validatron(each(max = 5))]
+        c: HashSet<u8>,
+        #[validatron(each(max = 5))]
+        d: BTreeSet<u8>,
+    }
+
+    let good = Foo {
+        a: &[1, 2],
+        b: [1, 2, 3],
+        c: vec![1, 2].into_iter().collect(),
+        d: vec![1, 2].into_iter().collect(),
+    };
+    assert!(good.validate().is_ok());
+
+    let bad = Foo {
+        a: &[6],
+        b: [1, 2, 6],
+        c: vec![6].into_iter().collect(),
+        d: vec![1, 6].into_iter().collect(),
+    };
+
+    match bad.validate().unwrap_err() {
+        Error::Structured(map) => assert_eq!(map.len(), 4),
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+#[test]
+fn each_nested() {
+    fn is_even(x: &u32) -> Result<(), Error> {
+        if x % 2 == 0 {
+            Ok(())
+        } else {
+            Err(Error::new("must be even"))
+        }
+    }
+
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(each(min_len = 1, each(function = "is_even")))]
+        a: Vec<Vec<u32>>,
+    }
+
+    assert!(Foo {
+        a: vec![vec![2], vec![4, 6]]
+    }
+    .validate()
+    .is_ok());
+    assert!(Foo { a: vec![vec![]] }.validate().is_err());
+    assert!(Foo {
+        a: vec![vec![2], vec![4, 5]]
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn each_enum_variant() {
+    #[derive(Validate)]
+    enum Foo {
+        Tuple(#[validatron(each(required))] Vec<Option<u8>>),
+        Struct {
+            #[validatron(each(min = 1))]
+            a: Vec<u8>,
+        },
+    }
+
+    assert!(Foo::Tuple(vec![Some(1)]).validate().is_ok());
+    assert!(Foo::Tuple(vec![Some(1), None]).validate().is_err());
+
+    assert!(Foo::Struct { a: vec![1] }.validate().is_ok());
+    assert!(Foo::Struct { a: vec![0] }.validate().is_err());
+}
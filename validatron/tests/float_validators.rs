@@ -0,0 +1,163 @@
+use validatron::{Error, Reason, Validate};
+
+#[derive(Clone, Validate)]
+struct Reading {
+    #[validatron(min = -90.0, max = 90.0)]
+    latitude: f64,
+    #[validatron(range(min = 0.0, max_exclusive = 360.0))]
+    heading: f32,
+    #[validatron(option_min = 0.0)]
+    altitude: Option<f64>,
+    #[validatron(min = 0.0, allow_nan)]
+    signal: f64,
+    #[validatron(finite)]
+    temperature: f64,
+    #[validatron(not_nan)]
+    pressure: Option<f64>,
+    #[validatron(multiple_of = 0.5)]
+    elevation: f64,
+    #[validatron(multiple_of = 15)]
+    minutes: u32,
+    #[validatron(max_decimal_places = 2)]
+    voltage: f64,
+}
+
+fn reading() -> Reading {
+    Reading {
+        latitude: 47.5,
+        heading: 359.5,
+        altitude: None,
+        signal: f64::NAN,
+        temperature: -3.25,
+        pressure: Some(1013.25),
+        elevation: 12.5,
+        minutes: 45,
+        voltage: 3.3,
+    }
+}
+
+fn check(x: &Reading) -> Result<(), Error> {
+    assert_eq!(x.is_valid(), x.validate().is_ok());
+    x.validate()
+}
+
+#[test]
+fn valid_readings() {
+    assert!(check(&reading()).is_ok());
+
+    let x = Reading {
+        altitude: Some(1.0),
+        pressure: None,
+        elevation: 0.3 * 10.0 - 1.5,
+        ..reading()
+    };
+    assert!(check(&x).is_ok());
+}
+
+#[test]
+fn comparisons_reject_nan() {
+    type Mutation = fn(&mut Reading);
+
+    // NaN fails both bounds of the latitude
+    let mutations: &[(&str, usize, Mutation)] = &[
+        ("latitude", 2, |x| x.latitude = f64::NAN),
+        ("heading", 1, |x| x.heading = f32::NAN),
+        ("altitude", 1, |x| x.altitude = Some(f64::NAN)),
+    ];
+
+    for (field, failures, mutate) in mutations {
+        let mut x = reading();
+        mutate(&mut x);
+
+        let e = check(&x).unwrap_err();
+        assert_eq!(e.flatten().count(), *failures, "{}", field);
+        assert!(e.to_string().starts_with(field), "{}", e);
+    }
+
+    // comparisons declared with allow_nan still reject other values
+    let x = Reading {
+        signal: -1.0,
+        ..reading()
+    };
+    assert!(check(&x).is_err());
+}
+
+#[test]
+fn numeric_properties() {
+    let x = Reading {
+        temperature: f64::INFINITY,
+        pressure: Some(f64::NAN),
+        elevation: 12.25,
+        minutes: 50,
+        voltage: 3.125,
+        ..reading()
+    };
+
+    assert_eq!(
+        check(&x).unwrap_err().to_string(),
+        [
+            "elevation: '12.25' must be a multiple of '0.5'",
+            "minutes: '50' must be a multiple of '15'",
+            "pressure: value must not be NaN",
+            "temperature: 'inf' must be a finite number",
+            "voltage: '3.125' must have at most 2 decimal places",
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        Reading {
+            voltage: 3.125,
+            ..reading()
+        }
+        .validate()
+        .unwrap_err(),
+        Error::build()
+            .at_named(
+                "voltage",
+                Reason::from_code("max_decimal_places")
+                    .with_param("max_decimal_places", 2u64)
+                    .with_param("value", 3.125)
+            )
+            .build()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn incomparable_values() {
+    use std::cmp::Ordering;
+    use validatron::validators::{max, max_allow_nan, min, min_allow_nan, range, range_allow_nan};
+
+    // a version is only ordered against versions of the same major release
+    #[derive(PartialEq)]
+    struct Version(u32, u32);
+
+    impl PartialOrd for Version {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            if self.0 == other.0 {
+                self.1.partial_cmp(&other.1)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl std::fmt::Display for Version {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}.{}", self.0, self.1)
+        }
+    }
+
+    assert!(min(&Version(1, 2), Version(1, 0)).is_ok());
+    assert!(min(&Version(2, 0), Version(1, 0)).is_err());
+    assert!(min_allow_nan(&Version(2, 0), Version(1, 0)).is_err());
+
+    assert!(min(&f64::NAN, 0.0).is_err());
+    assert!(min_allow_nan(&f64::NAN, 0.0).is_ok());
+    assert!(max_allow_nan(&f64::NAN, 0.0).is_ok());
+    assert!(max(&f64::NAN, 0.0).is_err());
+    assert!(range_allow_nan(&f64::NAN, 0.0..1.0).is_ok());
+    assert!(range(&f64::NAN, 0.0..1.0).is_err());
+    assert!(range_allow_nan(&-1.0, 0.0..1.0).is_err());
+}
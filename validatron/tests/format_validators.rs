@@ -0,0 +1,139 @@
+use std::borrow::Cow;
+
+use validatron::{Error, Reason, Validate};
+
+#[derive(Clone, Validate)]
+struct Station {
+    #[validatron(email)]
+    contact: String,
+    #[validatron(option_email)]
+    backup_contact: Option<String>,
+    #[validatron(url("https", "wss"))]
+    telemetry: String,
+    #[validatron(option_url)]
+    docs: Option<Cow<'static, str>>,
+    #[validatron(hostname)]
+    host: &'static str,
+    #[validatron(ip)]
+    address: String,
+    #[validatron(option_ipv4)]
+    ipv4: Option<String>,
+    #[validatron(ipv6)]
+    ipv6: String,
+    #[validatron(each(cidr))]
+    allowed: Vec<String>,
+}
+
+fn station() -> Station {
+    Station {
+        contact: "ops@example.com".to_string(),
+        backup_contact: None,
+        telemetry: "wss://telemetry.example.com:8443/stream".to_string(),
+        docs: Some("http://docs.example.com".into()),
+        host: "gs-1.example.com",
+        address: "2001:db8::1".to_string(),
+        ipv4: Some("192.0.2.1".to_string()),
+        ipv6: "::1".to_string(),
+        allowed: vec!["10.0.0.0/8".to_string(), "2001:db8::/32".to_string()],
+    }
+}
+
+#[test]
+fn valid_formats() {
+    let x = station();
+    assert!(x.validate().is_ok());
+    assert!(x.is_valid());
+
+    let x = Station {
+        docs: None,
+        ipv4: None,
+        ..station()
+    };
+    assert!(x.validate().is_ok());
+    assert!(x.is_valid());
+
+    // fully qualified, with a trailing dot
+    let x = Station {
+        host: "gs-1.example.com.",
+        ..station()
+    };
+    assert!(x.validate().is_ok());
+    assert!(x.is_valid());
+}
+
+#[test]
+fn invalid_formats() {
+    type Mutation = fn(&mut Station);
+
+    let mutations: &[(&str, Mutation)] = &[
+        ("contact", |x| x.contact = "ops".to_string()),
+        ("backup_contact", |x| {
+            x.backup_contact = Some("ops@".to_string())
+        }),
+        ("telemetry", |x| x.telemetry = "telemetry".to_string()),
+        ("docs", |x| x.docs = Some("docs.example.com".into())),
+        ("host", |x| x.host = "gs_1.example.com"),
+        ("address", |x| x.address = "localhost".to_string()),
+        ("ipv4", |x| x.ipv4 = Some("::1".to_string())),
+        ("ipv6", |x| x.ipv6 = "127.0.0.1".to_string()),
+        ("allowed", |x| x.allowed.push("10.0.0.0/33".to_string())),
+    ];
+
+    for (field, mutate) in mutations {
+        let mut x = station();
+        mutate(&mut x);
+
+        let e = x.validate().unwrap_err();
+        assert!(!x.is_valid(), "{}", field);
+        assert_eq!(e.flatten().count(), 1, "{}", field);
+        assert!(e.to_string().starts_with(field), "{}", e);
+    }
+}
+
+#[test]
+fn url_schemes() {
+    let x = Station {
+        telemetry: "HTTPS://telemetry.example.com".to_string(),
+        ..station()
+    };
+    assert!(x.validate().is_ok());
+
+    let x = Station {
+        telemetry: "http://telemetry.example.com".to_string(),
+        ..station()
+    };
+    assert!(!x.is_valid());
+    assert_eq!(
+        x.validate().unwrap_err(),
+        Error::build()
+            .at_named(
+                "telemetry",
+                Reason::from_code("url_scheme")
+                    .with_param("value", "http://telemetry.example.com")
+                    .with_param("schemes", "https, wss")
+            )
+            .build()
+            .unwrap_err()
+    );
+    assert_eq!(
+        x.validate().unwrap_err().to_string(),
+        "telemetry: 'http://telemetry.example.com' must be a URL using one of the schemes https, wss"
+    );
+}
+
+#[test]
+fn custom_messages() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(email, message = "{value} cannot receive mail")]
+        a: String,
+    }
+
+    let e = Foo {
+        a: "nobody".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(e.to_string(), "a: nobody cannot receive mail");
+}
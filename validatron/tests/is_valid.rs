@@ -0,0 +1,242 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap};
+
+use validatron::{Error, Result, Validate};
+
+// counts the allocations made by the current thread, so tests running in parallel do not
+// interfere with each other
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|x| x.set(x.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> (usize, T) {
+    let before = ALLOCATIONS.with(|x| x.get());
+    let output = f();
+    (ALLOCATIONS.with(|x| x.get()) - before, output)
+}
+
+fn is_even(x: &u64) -> bool {
+    x % 2 == 0
+}
+
+fn not_seven(x: &u64) -> Result<()> {
+    if *x == 7 {
+        Err(Error::new("seven"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Validate)]
+struct Window {
+    #[validatron(min = 1, max = 60)]
+    minutes: u64,
+    #[validatron(option_min = 0.0, option_max = 90.0)]
+    elevation: Option<f64>,
+}
+
+#[derive(Clone, Validate)]
+enum Target {
+    Satellite(#[validatron(predicate = "is_even")] u64),
+    Station {
+        #[validatron(min_len = 1, max_len = 3)]
+        antennas: Vec<u32>,
+    },
+}
+
+#[derive(Clone, Validate)]
+#[validatron(function = "not_overbooked")]
+struct Schedule {
+    #[validatron(equal = 1)]
+    version: u64,
+    #[validatron(lte_field = "end")]
+    start: u64,
+    end: u64,
+    #[validatron(required_if = "self.end > 100")]
+    approved_by: Option<u64>,
+    #[validatron(each(function = "not_seven"), when = "self.version == 1", max_len = 4)]
+    priorities: Vec<u64>,
+    #[validatron(keys(max = 100), values(max = 10))]
+    limits: BTreeMap<u64, u64>,
+    #[validatron]
+    windows: Vec<Window>,
+    #[validatron]
+    targets: HashMap<String, Target>,
+}
+
+fn not_overbooked(x: &Schedule) -> Result<()> {
+    if x.windows.len() > 5 {
+        Err(Error::new("overbooked"))
+    } else {
+        Ok(())
+    }
+}
+
+fn schedule() -> Schedule {
+    Schedule {
+        version: 1,
+        start: 10,
+        end: 20,
+        approved_by: None,
+        priorities: vec![1, 2],
+        limits: vec![(1, 1)].into_iter().collect(),
+        windows: vec![
+            Window {
+                minutes: 10,
+                elevation: Some(45.0),
+            };
+            3
+        ],
+        targets: vec![
+            ("a".to_string(), Target::Satellite(2)),
+            ("b".to_string(), Target::Station { antennas: vec![1] }),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+#[test]
+fn agrees_with_validate() {
+    type Mutation = fn(&mut Schedule);
+
+    let mutations: &[Mutation] = &[
+        |_| {},
+        |x| x.version = 2,
+        |x| x.start = 21,
+        |x| x.end = 101,
+        |x| {
+            x.end = 101;
+            x.approved_by = Some(1);
+        },
+        |x| x.priorities = vec![7],
+        |x| {
+            x.version = 2;
+            x.priorities = vec![7];
+        },
+        |x| x.priorities = vec![0; 5],
+        |x| {
+            x.limits.insert(101, 1);
+        },
+        |x| {
+            x.limits.insert(2, 11);
+        },
+        |x| x.windows[1].minutes = 0,
+        |x| x.windows[2].elevation = Some(91.0),
+        |x| x.windows[2].elevation = None,
+        |x| x.windows = vec![x.windows[0].clone(); 6],
+        |x| {
+            x.targets.insert("c".to_string(), Target::Satellite(3));
+        },
+        |x| {
+            x.targets
+                .insert("c".to_string(), Target::Station { antennas: vec![] });
+        },
+    ];
+
+    for (i, mutate) in mutations.iter().enumerate() {
+        let mut x = schedule();
+        mutate(&mut x);
+
+        assert_eq!(x.is_valid(), x.validate().is_ok(), "mutation {}", i);
+    }
+}
+
+#[test]
+fn valid_values_do_not_allocate() {
+    let x = schedule();
+    let windows = vec![
+        Window {
+            minutes: 1,
+            elevation: None
+        };
+        1000
+    ];
+
+    assert_eq!(allocations(|| x.is_valid()), (0, true));
+    assert_eq!(allocations(|| windows.is_valid()), (0, true));
+}
+
+#[test]
+fn invalid_values_do_not_allocate() {
+    let mut x = schedule();
+    x.windows[1].minutes = 0;
+
+    let mut windows = vec![
+        Window {
+            minutes: 1,
+            elevation: None
+        };
+        1000
+    ];
+    windows[500].minutes = 61;
+
+    assert_eq!(allocations(|| x.is_valid()), (0, false));
+    assert_eq!(allocations(|| windows.is_valid()), (0, false));
+    assert!(allocations(|| windows.validate()).0 > 0);
+}
+
+#[test]
+fn manual_implementations_and_std_collections() {
+    struct Manual(bool);
+
+    impl Validate for Manual {
+        fn validate(&self) -> Result<()> {
+            if self.0 {
+                Ok(())
+            } else {
+                Err(Error::new("invalid"))
+            }
+        }
+    }
+
+    assert!(Manual(true).is_valid());
+    assert!(!vec![Manual(true), Manual(false)].is_valid());
+    assert!(Some(Manual(true)).is_valid());
+    assert!(None::<Manual>.is_valid());
+    assert!(!Err::<Manual, ()>(()).is_valid());
+    assert!(!vec![("a", Manual(false))]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>()
+        .is_valid());
+}
+
+#[test]
+fn unique_only_allocates_for_more_than_a_few_items() {
+    #[derive(Validate)]
+    struct Stations {
+        #[validatron(unique)]
+        ids: Vec<u32>,
+    }
+
+    let few = Stations {
+        ids: (0..16).collect(),
+    };
+    let many = Stations {
+        ids: (0..1000).collect(),
+    };
+
+    assert_eq!(allocations(|| few.is_valid()), (0, true));
+
+    // duplicates among many items are found by hashing them into a set
+    let (count, valid) = allocations(|| many.is_valid());
+    assert!(count > 0);
+    assert!(valid);
+}
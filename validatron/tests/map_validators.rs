@@ -0,0 +1,144 @@
+use std::collections::{BTreeMap, HashMap};
+use validatron::{validators, Error, Location, Validate};
+
+fn is_identifier(x: &str) -> Result<(), Error> {
+    if x.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(())
+    } else {
+        Err(Error::new("is not a valid identifier"))
+    }
+}
+
+fn entry<'a>(e: &'a Error, field: &str, key: &str) -> &'a Error {
+    match e {
+        Error::Structured(fields) => match &fields[&Location::Named(field.to_string().into())] {
+            Error::Structured(keys) => &keys[&Location::Named(key.to_string().into())],
+            Error::Unstructured(_) => panic!("should not happen"),
+        },
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+#[test]
+fn map_keys() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(keys(function = "is_identifier"))]
+        a: HashMap<String, u32>,
+    }
+
+    let good = Foo {
+        a: vec![("station_1".to_string(), 1)].into_iter().collect(),
+    };
+    assert!(good.validate().is_ok());
+
+    let bad = Foo {
+        a: vec![("station_1".to_string(), 1), ("station 2".to_string(), 2)]
+            .into_iter()
+            .collect(),
+    };
+    let e = bad.validate().unwrap_err();
+
+    assert_eq!(
+        entry(&e, "a", "station 2"),
+        &Error::Structured(
+            vec![(
+                Location::Named("key".into()),
+                Error::new("is not a valid identifier")
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn map_values() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(values(min = 0, max = 10))]
+        a: BTreeMap<&'static str, i32>,
+    }
+
+    let good = Foo {
+        a: vec![("a", 0), ("b", 10)].into_iter().collect(),
+    };
+    assert!(good.validate().is_ok());
+
+    let bad = Foo {
+        a: vec![("a", -1), ("b", 5), ("c", 11)].into_iter().collect(),
+    };
+    let e = bad.validate().unwrap_err();
+
+    match &e {
+        Error::Structured(fields) => match &fields[&Location::Named("a".into())] {
+            Error::Structured(keys) => assert_eq!(keys.len(), 2),
+            Error::Unstructured(_) => panic!("should not happen"),
+        },
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+
+    assert_eq!(
+        entry(&e, "a", "c"),
+        &Error::Structured(
+            vec![(
+                Location::Named("value".into()),
+                validators::max(&11, 10).unwrap_err()
+            )]
+            .into_iter()
+            .collect()
+        )
+    );
+}
+
+#[test]
+fn map_keys_and_values() {
+    #[derive(Validate)]
+    enum Foo {
+        Map(#[validatron(keys(function = "is_identifier"), values(min = 1))] HashMap<String, u32>),
+    }
+
+    assert!(Foo::Map(vec![("a".into(), 1)].into_iter().collect())
+        .validate()
+        .is_ok());
+
+    let e = Foo::Map(vec![("a b".into(), 0)].into_iter().collect())
+        .validate()
+        .unwrap_err();
+
+    match e {
+        Error::Structured(fields) => match &fields[&Location::Index(0)] {
+            Error::Structured(keys) => match &keys[&Location::Named("a b".into())] {
+                Error::Structured(entry) => {
+                    assert!(entry.contains_key(&Location::Named("key".into())));
+                    assert!(entry.contains_key(&Location::Named("value".into())));
+                }
+                Error::Unstructured(_) => panic!("should not happen"),
+            },
+            Error::Unstructured(_) => panic!("should not happen"),
+        },
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+#[cfg(feature = "use-indexmap")]
+#[test]
+fn indexmap_keys_and_values() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(keys(function = "is_identifier"), values(max = 3))]
+        a: indexmap::IndexMap<String, u8>,
+    }
+
+    let good = Foo {
+        a: vec![("a".to_string(), 3)].into_iter().collect(),
+    };
+    assert!(good.validate().is_ok());
+
+    let bad = Foo {
+        a: vec![("a".to_string(), 4), ("b-c".to_string(), 1)]
+            .into_iter()
+            .collect(),
+    };
+    assert!(bad.validate().is_err());
+}
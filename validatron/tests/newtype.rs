@@ -2,6 +2,7 @@ use validatron::Validate;
 
 #[test]
 fn newtype_does_not_impl_validate() {
+    #[allow(dead_code)]
     #[derive(Validate)]
     struct NewType(i32);
 
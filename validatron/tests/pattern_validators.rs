@@ -0,0 +1,125 @@
+#![cfg(feature = "use-regex")]
+
+use std::borrow::Cow;
+
+use validatron::{Error, Reason, Validate};
+
+#[derive(Validate)]
+struct Flight {
+    #[validatron(pattern = "^[A-Z]{3}-\\d+$")]
+    number: String,
+    #[validatron(option_pattern = "^[A-Z]{3}-\\d+$")]
+    codeshare: Option<String>,
+    #[validatron(each(pattern = "^[A-Z]{4}$"))]
+    waypoints: Vec<Cow<'static, str>>,
+}
+
+#[derive(Validate)]
+enum Route {
+    Direct(#[validatron(pattern = "^[A-Z]{3}$")] &'static str),
+    Via {
+        #[validatron(
+            pattern = r"^[A-Z]{3}$",
+            message = "{value} is not an airport, expected {pattern}"
+        )]
+        airport: String,
+    },
+}
+
+fn flight() -> Flight {
+    Flight {
+        number: "ABC-123".to_string(),
+        codeshare: None,
+        waypoints: vec!["EGLL".into(), "LSZH".into()],
+    }
+}
+
+#[test]
+fn matching_values() {
+    let x = flight();
+    assert!(x.validate().is_ok());
+    assert!(x.is_valid());
+
+    let x = Flight {
+        codeshare: Some("XYZ-9".to_string()),
+        ..flight()
+    };
+    assert!(x.validate().is_ok());
+    assert!(x.is_valid());
+
+    assert!(Route::Direct("ZRH").validate().is_ok());
+    assert!(Route::Direct("ZRH").is_valid());
+}
+
+#[test]
+fn failures_name_the_pattern() {
+    let x = Flight {
+        number: "abc-123".to_string(),
+        codeshare: Some("XYZ".to_string()),
+        waypoints: vec!["EGLL".into(), "egll".into()],
+    };
+
+    assert!(!x.is_valid());
+    assert_eq!(
+        x.validate().unwrap_err(),
+        Error::build()
+            .at_named(
+                "number",
+                Reason::from_code("pattern")
+                    .with_param("value", "abc-123")
+                    .with_param("pattern", "^[A-Z]{3}-\\d+$")
+            )
+            .at_named(
+                "codeshare",
+                Reason::from_code("pattern")
+                    .with_param("value", "XYZ")
+                    .with_param("pattern", "^[A-Z]{3}-\\d+$")
+            )
+            .try_at_named(
+                "waypoints",
+                Error::build()
+                    .at_index(
+                        1,
+                        Reason::from_code("pattern")
+                            .with_param("value", "egll")
+                            .with_param("pattern", "^[A-Z]{4}$")
+                    )
+                    .build()
+            )
+            .build()
+            .unwrap_err()
+    );
+
+    assert_eq!(
+        Route::Direct("zrh").validate().unwrap_err().to_string(),
+        "[0]: 'zrh' does not match the pattern '^[A-Z]{3}$'"
+    );
+    assert_eq!(
+        Route::Via {
+            airport: "Zürich".to_string()
+        }
+        .validate()
+        .unwrap_err()
+        .to_string(),
+        "airport: Zürich is not an airport, expected ^[A-Z]{3}$"
+    );
+}
+
+#[test]
+fn patterns_too_large_to_compile_fail() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(pattern = r"^\w{500}$")]
+        a: String,
+    }
+
+    let x = Foo { a: "a".to_string() };
+
+    assert!(!x.is_valid());
+
+    let e = x.validate().unwrap_err();
+    let (path, reason) = e.flatten().next().unwrap();
+    assert_eq!(path.to_string(), "a");
+    assert_eq!(reason.code(), "invalid_pattern");
+    assert!(reason.message().contains("size limit"));
+}
@@ -9,6 +9,6 @@ fn test_struct_with_derive_required_validator() {
         a: Option<u64>,
     }
 
-    assert_eq!(Foo { a: None }.validate().is_ok(), false);
-    assert_eq!(Foo { a: Some(12) }.validate().is_ok(), true);
+    assert!(Foo { a: None }.validate().is_err());
+    assert!(Foo { a: Some(12) }.validate().is_ok());
 }
@@ -62,3 +62,152 @@ fn test_min_seq_len_map() {
     };
     assert!(f.validate().is_ok());
 }
+
+#[test]
+fn test_exact_seq_len() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(exact_len = 3)]
+        a: Vec<i32>,
+        #[validatron(len(exact = 2))]
+        b: [u8; 2],
+    }
+
+    let f = Foo {
+        a: vec![1, 2, 3],
+        b: [0; 2],
+    };
+    assert!(f.validate().is_ok());
+    assert!(f.is_valid());
+
+    for a in [vec![], vec![1, 2], vec![1, 2, 3, 4]] {
+        let f = Foo { a, b: [0; 2] };
+        assert!(f.validate().is_err());
+        assert!(!f.is_valid());
+    }
+}
+
+#[test]
+fn test_seq_len_range() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(len_range = "1..=3")]
+        a: Vec<i32>,
+        #[validatron(len(min = 1, max = 3))]
+        b: Vec<i32>,
+    }
+
+    for (len, valid) in [(0, false), (1, true), (3, true), (4, false)] {
+        let f = Foo {
+            a: vec![0; len],
+            b: vec![0; len],
+        };
+
+        let e = Foo {
+            a: vec![0; len],
+            b: vec![1],
+        }
+        .validate();
+
+        assert_eq!(f.validate().is_ok(), valid);
+        assert_eq!(f.is_valid(), valid);
+        assert_eq!(e.is_ok(), valid);
+
+        if let Err(e) = e {
+            assert_eq!(
+                e.to_string(),
+                format!(
+                    "a: sequence must have a number of elements within 1..=3, it has {}",
+                    len
+                )
+            );
+        }
+    }
+}
+
+#[test]
+fn test_seq_len_without_iterating() {
+    use std::borrow::Cow;
+    use std::collections::{BTreeSet, LinkedList};
+
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(min_len = 1)]
+        a: LinkedList<i32>,
+        #[validatron(max_len = 2)]
+        b: BTreeSet<i32>,
+        #[validatron(len_range = "1..=3")]
+        c: Box<[i32]>,
+        #[validatron(max_len = 3)]
+        d: Cow<'static, [u8]>,
+        // text without a unit is measured in bytes
+        #[validatron(exact_len = 3)]
+        e: String,
+    }
+
+    let f = Foo {
+        a: LinkedList::from([1]),
+        b: BTreeSet::from([1, 2]),
+        c: vec![1].into(),
+        d: Cow::Borrowed(b"abc"),
+        e: "abc".to_string(),
+    };
+    assert!(f.validate().is_ok());
+    assert!(f.is_valid());
+
+    let f = Foo {
+        a: LinkedList::new(),
+        b: BTreeSet::from([1, 2, 3]),
+        c: vec![].into(),
+        d: Cow::Owned(vec![0; 4]),
+        e: "ab".to_string(),
+    };
+    assert_eq!(f.validate().unwrap_err().flatten().count(), 5);
+    assert!(!f.is_valid());
+}
+
+#[test]
+fn test_seq_len_of_other_iterables() {
+    // iterables without a known length are measured by counting their items
+    struct Countdown(usize);
+
+    impl IntoIterator for &Countdown {
+        type Item = usize;
+        type IntoIter = std::iter::Rev<std::ops::Range<usize>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            (0..self.0).rev()
+        }
+    }
+
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(min_len = 2, max_len = 3)]
+        a: Countdown,
+    }
+
+    assert!(Foo { a: Countdown(2) }.validate().is_ok());
+    assert!(Foo { a: Countdown(3) }.is_valid());
+    assert!(Foo { a: Countdown(1) }.validate().is_err());
+    assert!(!Foo { a: Countdown(4) }.is_valid());
+}
+
+#[test]
+fn test_seq_len_of_iterators() {
+    use validatron::checks;
+    use validatron::length::Len;
+    use validatron::validators::{is_exact_length, is_max_length, is_min_length};
+
+    let x = [1, 2, 3, 4, 5];
+    let odd = || x.iter().filter(|x| **x % 2 == 1);
+
+    assert!(is_min_length(odd(), 3).is_ok());
+    assert!(is_min_length(odd(), 4).is_err());
+    assert!(is_max_length(odd(), 2).is_err());
+    assert!(is_exact_length(x.iter().skip(2), 3).is_ok());
+    assert!(checks::is_min_length(odd(), 3));
+    assert!(!checks::is_max_length(odd(), 2));
+
+    assert!(is_max_length(Len::of("año"), 4).is_ok());
+    assert!(is_max_length(Len::of("año"), 3).is_err());
+}
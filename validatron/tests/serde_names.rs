@@ -0,0 +1,134 @@
+#![cfg(feature = "use-serde")]
+
+use serde::Deserialize;
+use validatron::{Error, Location, Validate};
+
+fn locations(e: Error) -> Vec<Location> {
+    match e {
+        Error::Structured(map) => map.into_keys().collect(),
+        Error::Unstructured(_) => panic!("should not happen"),
+    }
+}
+
+fn named(names: &[&'static str]) -> Vec<Location> {
+    names.iter().map(|x| Location::Named((*x).into())).collect()
+}
+
+#[test]
+fn serde_rename_all() {
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    struct Foo {
+        #[validatron(min = 1)]
+        out_a: u64,
+        #[validatron(min = 1)]
+        #[serde(rename = "B")]
+        out_b: u64,
+        #[validatron(min = 1)]
+        r#type: u64,
+    }
+
+    let e = Foo {
+        out_a: 0,
+        out_b: 0,
+        r#type: 0,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(locations(e), named(&["B", "outA", "type"]));
+}
+
+#[test]
+fn serde_rename_deserialize() {
+    #[derive(Deserialize, Validate)]
+    struct Foo {
+        #[validatron(min = 1)]
+        #[serde(rename(serialize = "ser", deserialize = "de"))]
+        a: u64,
+    }
+
+    let e = Foo { a: 0 }.validate().unwrap_err();
+    assert_eq!(locations(e), named(&["de"]));
+}
+
+#[test]
+fn serde_flatten() {
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "kebab-case")]
+    struct Inner {
+        #[validatron(min = 1)]
+        in_a: u64,
+    }
+
+    #[derive(Deserialize, Validate)]
+    struct Outer {
+        #[validatron(min = 1)]
+        out_a: u64,
+        #[validatron]
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    let e = Outer {
+        out_a: 0,
+        inner: Inner { in_a: 0 },
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(locations(e), named(&["in-a", "out_a"]));
+}
+
+#[test]
+fn validatron_rename_and_opt_out() {
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    struct Foo {
+        #[validatron(min = 1, rename = "explicit")]
+        out_a: u64,
+        #[validatron(min = 1)]
+        out_b: u64,
+    }
+
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    #[validatron(ignore_serde)]
+    struct Bar {
+        #[validatron(min = 1)]
+        #[serde(rename = "B")]
+        out_a: u64,
+        #[validatron(min = 1)]
+        #[validatron(rename = "explicit")]
+        out_b: u64,
+    }
+
+    let e = Foo { out_a: 0, out_b: 0 }.validate().unwrap_err();
+    assert_eq!(locations(e), named(&["explicit", "outB"]));
+
+    let e = Bar { out_a: 0, out_b: 0 }.validate().unwrap_err();
+    assert_eq!(locations(e), named(&["explicit", "out_a"]));
+}
+
+#[test]
+fn enum_variant_fields() {
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all_fields = "camelCase")]
+    enum Foo {
+        A {
+            #[validatron(min = 1)]
+            out_a: u64,
+        },
+        #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+        B {
+            #[validatron(min = 1)]
+            out_a: u64,
+        },
+    }
+
+    let e = Foo::A { out_a: 0 }.validate().unwrap_err();
+    assert_eq!(locations(e), named(&["outA"]));
+
+    let e = Foo::B { out_a: 0 }.validate().unwrap_err();
+    assert_eq!(locations(e), named(&["OUT_A"]));
+}
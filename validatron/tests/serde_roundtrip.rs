@@ -0,0 +1,201 @@
+#![cfg(feature = "use-serde")]
+
+use std::collections::HashMap;
+
+use validatron::{Error, Location, Reason, Validate};
+
+fn example() -> Error {
+    Error::build()
+        .at_named(
+            "a",
+            Reason::new("min", "too small")
+                .with_param("min", 42)
+                .with_param("value", -1)
+                .with_param("ratio", 0.5)
+                .with_param("strict", true)
+                .with_param("name", "foo"),
+        )
+        .try_at_named(
+            "b",
+            Error::build()
+                .at_index(0, "index")
+                .at_named("0", "numeric name")
+                .at_named("\\x", "escaped name")
+                .try_at_index(2, Error::build().at_named("c", "nested").build())
+                .build(),
+        )
+        .build()
+        .unwrap_err()
+}
+
+#[test]
+fn json_roundtrip() {
+    let e = example();
+    let json = serde_json::to_string(&e).unwrap();
+
+    assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), e);
+}
+
+#[test]
+fn yaml_roundtrip() {
+    let e = example();
+    let yaml = serde_yaml::to_string(&e).unwrap();
+
+    assert_eq!(serde_yaml::from_str::<Error>(&yaml).unwrap(), e);
+}
+
+#[test]
+fn json_keys() {
+    let e = Error::build()
+        .at_index(0, "index")
+        .at_named("0", "numeric name")
+        .at_named("007", "name")
+        .build()
+        .unwrap_err();
+
+    let json = serde_json::to_value(&e).unwrap();
+    let keys: Vec<_> = json.as_object().unwrap().keys().cloned().collect();
+    assert_eq!(keys, vec!["0", "007", "\\0"]);
+}
+
+#[test]
+fn integer_map_keys() {
+    // pins the wire format, numeric map keys are escaped so they are not read back as indexes
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(values(min = 1))]
+        a: HashMap<u32, u8>,
+        #[validatron(each(min = 1))]
+        b: Vec<u8>,
+    }
+
+    let e = Foo {
+        a: vec![(5, 0)].into_iter().collect(),
+        b: vec![0],
+    }
+    .validate()
+    .unwrap_err();
+
+    let json = serde_json::to_value(&e).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "a": {
+                "\\5": {
+                    "value": [{
+                        "code": "min",
+                        "message": "'0' must be greater than or equal to '1'",
+                        "params": {"min": 1, "value": 0}
+                    }]
+                }
+            },
+            "b": {
+                "0": [{
+                    "code": "min",
+                    "message": "'0' must be greater than or equal to '1'",
+                    "params": {"min": 1, "value": 0}
+                }]
+            }
+        })
+    );
+    assert_eq!(serde_json::from_value::<Error>(json).unwrap(), e);
+}
+
+#[test]
+fn unstructured_roundtrip() {
+    let e = Error::new("root");
+    let json = serde_json::to_string(&e).unwrap();
+
+    assert_eq!(json, r#"[{"code":"custom","message":"root","params":{}}]"#);
+    assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), e);
+}
+
+#[test]
+fn derived_roundtrip() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(min = 1)]
+        a: u64,
+        #[validatron(each(max_len = 1))]
+        b: Vec<Vec<u8>>,
+        #[validatron]
+        c: HashMap<u32, Bar>,
+    }
+
+    #[derive(Validate)]
+    struct Bar(#[validatron(required)] Option<u8>);
+
+    let e = Foo {
+        a: 0,
+        b: vec![vec![], vec![1, 2]],
+        c: vec![(3, Bar(None))].into_iter().collect(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let json = serde_json::to_string(&e).unwrap();
+    assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), e);
+
+    let yaml = serde_yaml::to_string(&e).unwrap();
+    assert_eq!(serde_yaml::from_str::<Error>(&yaml).unwrap(), e);
+}
+
+#[test]
+fn truncated_roundtrip() {
+    use validatron::ValidationOptions;
+
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(min = 1)]
+        errors: u64,
+        #[validatron(min = 1)]
+        b: u64,
+    }
+
+    let e = Foo { errors: 0, b: 0 }
+        .validate_with_options(ValidationOptions::new().fail_fast())
+        .unwrap_err();
+
+    let json = serde_json::to_value(&e).unwrap();
+    let keys: Vec<_> = json.as_object().unwrap().keys().cloned().collect();
+    assert_eq!(keys, vec!["\\truncated", "errors"]);
+
+    let e2 = serde_json::from_value::<Error>(json).unwrap();
+    assert!(e2.is_truncated());
+    assert_eq!(e2, e);
+}
+
+#[test]
+fn invalid_location() {
+    assert!(serde_json::from_str::<Location>("-1").is_err());
+    assert!(serde_json::from_str::<Error>("42").is_err());
+}
+
+#[test]
+fn custom_message_roundtrip() {
+    use validatron::catalog::TemplateCatalog;
+
+    let e = Error::from(
+        Reason::from_code("min")
+            .with_param("min", 42)
+            .with_message("altitude must be at least {min} km"),
+    );
+
+    let json = serde_json::to_value(&e).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "code": "min",
+            "message": "altitude must be at least {min} km",
+            "params": {"min": 42},
+            "custom": true
+        }])
+    );
+
+    let catalog = TemplateCatalog::new().with_template("de", "min", "muss mindestens {min} sein");
+    let e = serde_json::from_value::<Error>(json)
+        .unwrap()
+        .localize(&catalog, "de");
+
+    assert_eq!(e.to_string(), "altitude must be at least {min} km");
+}
@@ -41,6 +41,7 @@ fn newtype_struct_derive() {
 
     assert!(Unit().validate().is_ok());
 
+    #[allow(dead_code)]
     #[derive(Validate)]
     struct NewType(u32);
     assert!(NewType(12).validate().is_ok());
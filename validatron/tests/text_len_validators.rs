@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use validatron::validators::Unit;
+use validatron::{Error, Reason, Validate};
+
+#[derive(Validate)]
+struct Station {
+    #[validatron(min_len = 1, max_len = 8, unit = "chars")]
+    name: String,
+    #[validatron(max_len = 8, unit = "bytes")]
+    code: Cow<'static, str>,
+    #[validatron(len(exact = 2, unit = "chars"))]
+    country: &'static str,
+    #[validatron(each(len_range = "1..=4", unit = "chars"))]
+    aliases: Vec<String>,
+}
+
+fn station() -> Station {
+    Station {
+        name: "Zürich".to_string(),
+        code: "ZRH".into(),
+        country: "CH",
+        aliases: vec!["ZH".to_string()],
+    }
+}
+
+#[test]
+fn units() {
+    let x = station();
+    assert!(x.validate().is_ok());
+    assert!(x.is_valid());
+
+    // 7 bytes but 6 chars
+    assert_eq!(Unit::Bytes.count("Zürich"), 7);
+    assert_eq!(Unit::Chars.count("Zürich"), 6);
+
+    let x = Station {
+        code: "Zürich!!".into(),
+        ..station()
+    };
+    assert!(!x.is_valid());
+    assert_eq!(
+        x.validate().unwrap_err(),
+        Error::build()
+            .at_named(
+                "code",
+                Reason::from_code("max_text_len")
+                    .with_param("max_len", 8u64)
+                    .with_param("len", 9u64)
+                    .with_param("unit", "bytes")
+            )
+            .build()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn failures() {
+    let x = Station {
+        name: String::new(),
+        code: "ZRH".into(),
+        country: "CHE",
+        aliases: vec!["ZH".to_string(), String::new(), "Zürich".to_string()],
+    };
+
+    assert!(!x.is_valid());
+    assert_eq!(
+        x.validate().unwrap_err().to_string(),
+        [
+            "aliases[1]: text must have a length within 1..=4 chars, it has 0",
+            "aliases[2]: text must have a length within 1..=4 chars, it has 6",
+            "country: text must have exactly 2 chars, it has 3",
+            "name: text is too short, it has 0 chars but the minimum is 1",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn custom_messages() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(len(min = 2, max = 4, unit = "chars"), message = "{len_range} {unit}")]
+        a: String,
+        #[validatron(min_len = 2, unit = "bytes", message = "at least {min_len} {unit}")]
+        b: String,
+    }
+
+    let e = Foo {
+        a: "a".to_string(),
+        b: "b".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(e.to_string(), "a: 2..=4 chars\nb: at least 2 bytes");
+}
+
+#[cfg(feature = "use-unicode-segmentation")]
+#[test]
+fn graphemes() {
+    use validatron::validators::is_min_text_length;
+
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(max_len = 1, unit = "graphemes")]
+        flag: String,
+    }
+
+    // a flag is a single grapheme made of two chars
+    let flag = "\u{1F1E8}\u{1F1ED}";
+    assert_eq!(Unit::Chars.count(flag), 2);
+
+    let x = Foo {
+        flag: flag.to_string(),
+    };
+    assert!(x.validate().is_ok());
+    assert!(x.is_valid());
+
+    let x = Foo {
+        flag: format!("{}{}", flag, flag),
+    };
+    assert!(x.validate().is_err());
+    assert!(!x.is_valid());
+    assert!(is_min_text_length(flag, 1, Unit::Graphemes).is_ok());
+}
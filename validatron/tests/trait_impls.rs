@@ -13,7 +13,7 @@ impl Validate for Dummy {
         }
     }
 }
-impl<'a> Validate for &'a Dummy {
+impl Validate for &Dummy {
     fn validate(&self) -> Result<()> {
         (*self).validate()
     }
@@ -24,14 +24,14 @@ fn option() {
     let a: Option<Dummy> = None;
     assert!(a.validate().is_ok());
 
-    assert_eq!(Validate::validate(&a).is_ok(), true);
-    assert_eq!(a.validate().is_ok(), true);
+    assert!(Validate::validate(&a).is_ok());
+    assert!(a.validate().is_ok());
 
     let b = Some(Dummy(true));
-    assert_eq!(b.validate().is_ok(), true);
+    assert!(b.validate().is_ok());
 
     let b = Some(Dummy(false));
-    assert_eq!(b.validate().is_ok(), false);
+    assert!(b.validate().is_err());
 }
 
 #[test]
@@ -53,10 +53,10 @@ fn vec() {
         Error::Structured(map) => {
             assert_eq!(map.len(), 2);
 
-            assert_eq!(map.contains_key(&Location::Index(0)), false);
-            assert_eq!(map.contains_key(&Location::Index(1)), true);
-            assert_eq!(map.contains_key(&Location::Index(2)), false);
-            assert_eq!(map.contains_key(&Location::Index(3)), true);
+            assert!(!map.contains_key(&Location::Index(0)));
+            assert!(map.contains_key(&Location::Index(1)));
+            assert!(!map.contains_key(&Location::Index(2)));
+            assert!(map.contains_key(&Location::Index(3)));
         }
         _ => panic!("cannot happen"),
     }
@@ -81,10 +81,10 @@ fn vecdeque() {
         Error::Structured(map) => {
             assert_eq!(map.len(), 2);
 
-            assert_eq!(map.contains_key(&Location::Index(0)), false);
-            assert_eq!(map.contains_key(&Location::Index(1)), true);
-            assert_eq!(map.contains_key(&Location::Index(2)), false);
-            assert_eq!(map.contains_key(&Location::Index(3)), true);
+            assert!(!map.contains_key(&Location::Index(0)));
+            assert!(map.contains_key(&Location::Index(1)));
+            assert!(!map.contains_key(&Location::Index(2)));
+            assert!(map.contains_key(&Location::Index(3)));
         }
         _ => panic!("cannot happen"),
     }
@@ -109,10 +109,10 @@ fn linkedlist() {
         Error::Structured(map) => {
             assert_eq!(map.len(), 2);
 
-            assert_eq!(map.contains_key(&Location::Index(0)), false);
-            assert_eq!(map.contains_key(&Location::Index(1)), true);
-            assert_eq!(map.contains_key(&Location::Index(2)), false);
-            assert_eq!(map.contains_key(&Location::Index(3)), true);
+            assert!(!map.contains_key(&Location::Index(0)));
+            assert!(map.contains_key(&Location::Index(1)));
+            assert!(!map.contains_key(&Location::Index(2)));
+            assert!(map.contains_key(&Location::Index(3)));
         }
         _ => panic!("cannot happen"),
     }
@@ -0,0 +1,9 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(max_len = 8, unit = "graphemes")]
+    a: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(pattern = "^[A-Z{3}$")]
+    a: String,
+    #[validatron(option_pattern = 42)]
+    b: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+
+    #[cfg(feature = "use-regex")]
+    t.compile_fail("tests/ui-regex/*.rs");
+
+    #[cfg(not(feature = "use-unicode-segmentation"))]
+    t.compile_fail("tests/ui-no-unicode-segmentation/*.rs");
+}
@@ -0,0 +1,11 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(finite, allow_nan)]
+    a: f64,
+    #[validatron(min = 0.0, allow_nan = true)]
+    b: f64,
+}
+
+fn main() {}
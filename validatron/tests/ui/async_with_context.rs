@@ -0,0 +1,14 @@
+use validatron::{Result, Validate};
+
+async fn check(_: &u32) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Validate)]
+#[validatron(context = "()")]
+struct Foo {
+    #[validatron(async_function = "check")]
+    a: u32,
+}
+
+fn main() {}
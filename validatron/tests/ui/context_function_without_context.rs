@@ -0,0 +1,13 @@
+use validatron::{Result, Validate};
+
+fn check(_: &u32, _: &()) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(context_function = "check")]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(each())]
+    a: Vec<u64>,
+}
+
+fn main() {}
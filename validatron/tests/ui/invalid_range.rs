@@ -0,0 +1,13 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(range())]
+    a: u64,
+    #[validatron(range(min = 0, min_exclusive = 1))]
+    b: u64,
+    #[validatron(range(low = 0))]
+    c: u64,
+}
+
+fn main() {}
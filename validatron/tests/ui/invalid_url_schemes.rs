@@ -0,0 +1,11 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(url())]
+    a: String,
+    #[validatron(option_url(https))]
+    b: Option<String>,
+}
+
+fn main() {}
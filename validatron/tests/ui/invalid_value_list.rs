@@ -0,0 +1,17 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(one_of())]
+    a: String,
+    #[validatron(none_of)]
+    b: String,
+    #[validatron(one_of(nominal, safe))]
+    c: String,
+    #[validatron(one_of_ignore_case("safe", 1))]
+    d: String,
+    #[validatron(none_of = 42)]
+    e: u32,
+}
+
+fn main() {}
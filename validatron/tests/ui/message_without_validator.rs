@@ -0,0 +1,9 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(message = "something went wrong")]
+    a: u64,
+}
+
+fn main() {}
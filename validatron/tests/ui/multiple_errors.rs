@@ -0,0 +1,15 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+#[validatron(function = 1)]
+enum Foo {
+    A(#[validatron(mni = 3)] u64),
+    B {
+        #[validatron(required, requried)]
+        a: Option<u64>,
+        #[validatron(max = "")]
+        b: u64,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+union Foo {
+    a: u64,
+    b: f64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(min = 42, message = "must be at most {max}")]
+    a: u64,
+}
+
+fn main() {}
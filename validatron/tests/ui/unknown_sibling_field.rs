@@ -0,0 +1,10 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Window {
+    start_time: u64,
+    #[validatron(gte_field = "start")]
+    end_time: u64,
+}
+
+fn main() {}
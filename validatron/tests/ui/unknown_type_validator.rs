@@ -0,0 +1,9 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+#[validatron(fucntion = "check_foo")]
+struct Foo {
+    a: u64,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(min_len = 1, unit = "words")]
+    a: String,
+    #[validatron(unit = "chars")]
+    b: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    #[validatron(mni = 3)]
+    a: u64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validatron::Validate;
+
+#[derive(Validate)]
+struct Foo {
+    enabled: bool,
+    #[validatron(when = "self.enabled")]
+    a: Option<u64>,
+}
+
+fn main() {}
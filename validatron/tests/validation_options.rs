@@ -0,0 +1,202 @@
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
+use validatron::{Error, Result, Validate, ValidationOptions};
+
+thread_local! {
+    static CHECKED: Cell<usize> = const { Cell::new(0) };
+}
+
+// counts how many times it has been called, to observe that validation stopped early
+fn counted(_: &u64) -> Result<()> {
+    CHECKED.with(|x| x.set(x.get() + 1));
+    Err(Error::new("always fails"))
+}
+
+fn checked() -> usize {
+    CHECKED.with(|x| x.replace(0))
+}
+
+fn codes(e: &Error) -> Vec<String> {
+    e.flatten().map(|(_, r)| r.code().to_string()).collect()
+}
+
+#[derive(Validate)]
+struct Row {
+    #[validatron(function = "counted")]
+    a: u64,
+    #[validatron(function = "counted")]
+    b: u64,
+}
+
+fn rows(n: usize) -> Vec<Row> {
+    (0..n).map(|_| Row { a: 0, b: 0 }).collect()
+}
+
+#[test]
+fn exhaustive_by_default() {
+    let rows = rows(100);
+
+    let e = rows
+        .validate_with_options(ValidationOptions::default())
+        .unwrap_err();
+
+    assert!(!e.is_truncated());
+    assert_eq!(e.flatten().count(), 200);
+    assert_eq!(checked(), 200);
+
+    assert_eq!(rows.validate(), Err(e));
+    assert_eq!(checked(), 200);
+}
+
+#[test]
+fn max_errors_stops_early() {
+    let e = rows(100)
+        .validate_with_options(ValidationOptions::new().max_errors(3))
+        .unwrap_err();
+
+    assert!(e.is_truncated());
+    assert_eq!(checked(), 3);
+    assert_eq!(
+        e.to_string(),
+        "validation stopped after 3 errors\n\
+         [0].a: always fails\n\
+         [0].b: always fails\n\
+         [1].a: always fails"
+    );
+}
+
+#[test]
+fn fail_fast() {
+    let e = rows(100)
+        .validate_with_options(ValidationOptions::new().fail_fast())
+        .unwrap_err();
+
+    assert!(e.is_truncated());
+    assert_eq!(checked(), 1);
+    assert_eq!(codes(&e), vec!["truncated", "custom"]);
+}
+
+#[test]
+fn exact_budget_is_not_truncated() {
+    let e = rows(1)
+        .validate_with_options(ValidationOptions::new().max_errors(2))
+        .unwrap_err();
+
+    assert!(!e.is_truncated());
+    assert_eq!(checked(), 2);
+
+    assert!(rows(0)
+        .validate_with_options(ValidationOptions::new().fail_fast())
+        .is_ok());
+}
+
+#[test]
+fn zero_max_errors_checks_nothing() {
+    let e = rows(1)
+        .validate_with_options(ValidationOptions::new().max_errors(0))
+        .unwrap_err();
+
+    assert!(e.is_truncated());
+    assert_eq!(codes(&e), vec!["truncated"]);
+    assert_eq!(checked(), 0);
+
+    #[derive(Validate)]
+    struct Valid {
+        #[validatron(min = 1)]
+        a: u64,
+    }
+
+    // nothing was checked, so valid data is not reported as valid either
+    let e = Valid { a: 1 }
+        .validate_with_options(ValidationOptions::new().max_errors(0))
+        .unwrap_err();
+    assert!(e.is_truncated());
+}
+
+#[test]
+fn field_collections_and_maps() {
+    #[derive(Validate)]
+    struct Schedule {
+        #[validatron(each(function = "counted"))]
+        passes: Vec<u64>,
+        #[validatron(values(function = "counted"))]
+        windows: BTreeMap<String, u64>,
+        #[validatron]
+        rows: BTreeMap<String, Row>,
+    }
+
+    let schedule = Schedule {
+        passes: vec![0; 10],
+        windows: (0..10).map(|i| (i.to_string(), 0)).collect(),
+        rows: (0..10)
+            .map(|i| (i.to_string(), Row { a: 0, b: 0 }))
+            .collect(),
+    };
+
+    for max_errors in [1, 5, 12, 25] {
+        let e = schedule
+            .validate_with_options(ValidationOptions::new().max_errors(max_errors))
+            .unwrap_err();
+
+        assert!(e.is_truncated());
+        assert_eq!(checked(), max_errors);
+        assert_eq!(e.flatten().count(), max_errors + 1);
+    }
+
+    assert!(schedule.validate().is_err());
+    assert_eq!(checked(), 40);
+}
+
+#[test]
+fn enums_and_type_level_checks() {
+    fn whole(_: &Target) -> Result<()> {
+        Err(Error::new("whole"))
+    }
+
+    #[derive(Validate)]
+    #[validatron(function = "whole")]
+    enum Target {
+        Station {
+            #[validatron(function = "counted")]
+            a: u64,
+            #[validatron(min = 1)]
+            b: u64,
+        },
+    }
+
+    let target = Target::Station { a: 0, b: 0 };
+
+    let e = target
+        .validate_with_options(ValidationOptions::new().max_errors(2))
+        .unwrap_err();
+    assert_eq!(codes(&e), vec!["truncated", "custom", "min"]);
+    assert_eq!(checked(), 1);
+
+    let e = target
+        .validate_with_options(ValidationOptions::new().max_errors(3))
+        .unwrap_err();
+    assert!(!e.is_truncated());
+    assert_eq!(codes(&e), vec!["custom", "min", "custom"]);
+    assert_eq!(checked(), 1);
+}
+
+#[test]
+fn manual_implementations_are_charged() {
+    struct Manual;
+
+    impl Validate for Manual {
+        fn validate(&self) -> Result<()> {
+            let mut eb = Error::build();
+            eb.at_named("a", "first").at_named("b", "second");
+            eb.build()
+        }
+    }
+
+    let e = vec![Manual, Manual]
+        .validate_with_options(ValidationOptions::new().max_errors(1))
+        .unwrap_err();
+
+    assert!(e.is_truncated());
+    assert_eq!(codes(&e), vec!["truncated", "custom", "custom"]);
+}
@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use validatron::{Error, Reason, Validate};
+
+const RESERVED: &[&str] = &["localhost", "test"];
+const CHANNELS: [u8; 3] = [1, 6, 11];
+
+#[derive(Clone, Validate)]
+struct Config {
+    #[validatron(one_of("nominal", "safe", "eclipse"))]
+    mode: String,
+    #[validatron(one_of_ignore_case("s", "x", "ka"))]
+    band: Cow<'static, str>,
+    #[validatron(none_of = "RESERVED")]
+    hostname: &'static str,
+    #[validatron(none_of_ignore_case = "RESERVED")]
+    alias: String,
+    #[validatron(one_of = "CHANNELS")]
+    channel: u8,
+    #[validatron(one_of(-1, 0, 1))]
+    polarity: i8,
+    #[validatron(each(one_of(9600, 115200)))]
+    baud_rates: Vec<u32>,
+}
+
+fn config() -> Config {
+    Config {
+        mode: "safe".to_string(),
+        band: "Ka".into(),
+        hostname: "gs-1",
+        alias: "primary".to_string(),
+        channel: 6,
+        polarity: -1,
+        baud_rates: vec![9600],
+    }
+}
+
+fn check(x: &Config) -> Result<(), Error> {
+    assert_eq!(x.is_valid(), x.validate().is_ok());
+    x.validate()
+}
+
+#[test]
+fn valid_values() {
+    assert!(check(&config()).is_ok());
+
+    let x = Config {
+        band: "X".into(),
+        alias: "Test-1".to_string(),
+        polarity: 1,
+        baud_rates: vec![],
+        ..config()
+    };
+    assert!(check(&x).is_ok());
+}
+
+#[test]
+fn invalid_values() {
+    type Mutation = fn(&mut Config);
+
+    let mutations: &[(&str, Mutation)] = &[
+        ("mode", |x| x.mode = "Safe".to_string()),
+        ("band", |x| x.band = "ku".into()),
+        ("hostname", |x| x.hostname = "localhost"),
+        ("alias", |x| x.alias = "LocalHost".to_string()),
+        ("channel", |x| x.channel = 2),
+        ("polarity", |x| x.polarity = 2),
+        ("baud_rates[1]", |x| x.baud_rates.push(19200)),
+    ];
+
+    for (path, mutate) in mutations {
+        let mut x = config();
+        mutate(&mut x);
+
+        let e = check(&x).unwrap_err();
+        let paths: Vec<_> = e.flatten().map(|(path, _)| path.to_string()).collect();
+        assert_eq!(paths, [*path], "{}", e);
+    }
+}
+
+#[test]
+fn messages_list_the_values() {
+    let x = Config {
+        mode: "off".to_string(),
+        hostname: "test",
+        ..config()
+    };
+
+    assert_eq!(
+        check(&x).unwrap_err(),
+        Error::build()
+            .at_named(
+                "hostname",
+                Reason::from_code("none_of")
+                    .with_param("none_of", "localhost, test")
+                    .with_param("value", "test")
+            )
+            .at_named(
+                "mode",
+                Reason::from_code("one_of")
+                    .with_param("one_of", "nominal, safe, eclipse")
+                    .with_param("value", "off")
+            )
+            .build()
+            .unwrap_err()
+    );
+    assert_eq!(
+        x.validate().unwrap_err().to_string(),
+        "hostname: 'test' must not be one of localhost, test\n\
+         mode: 'off' must be one of nominal, safe, eclipse"
+    );
+}
+
+#[test]
+fn custom_messages() {
+    #[derive(Validate)]
+    struct Foo {
+        #[validatron(one_of("a", "b"), message = "expected {one_of}, got {value}")]
+        a: String,
+        #[validatron(none_of = "RESERVED", message = "{value} is reserved ({none_of})")]
+        b: String,
+    }
+
+    let e = Foo {
+        a: "c".to_string(),
+        b: "test".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        e.to_string(),
+        "a: expected a, b, got c\nb: test is reserved (localhost, test)"
+    );
+}
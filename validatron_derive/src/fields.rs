@@ -0,0 +1,200 @@
+//! The context of the field a validator is declared on.
+//!
+//! Cross-field validators such as `gte_field = "start_time"` and expressions such as
+//! `min = "self.start_time"` refer to sibling fields. These are accessed through `self`
+//! in structs, but are bound by reference when matching on an enum variant.
+
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+
+use crate::naming::{FieldLocation, Naming};
+
+/// How the derived type is validated
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Mode {
+    /// validated with a context, see `#[validatron(context = "...")]`
+    pub(crate) context: bool,
+    /// validated asynchronously, see `#[validatron(async_function = "...")]`
+    pub(crate) asynchronous: bool,
+    /// only checking whether the type is valid, see `Validate::is_valid`
+    pub(crate) predicate: bool,
+}
+
+impl Mode {
+    /// whether checks are charged to an error budget, see `Validate::validate_within`
+    pub(crate) fn budgeted(&self) -> bool {
+        !self.context && !self.asynchronous && !self.predicate
+    }
+}
+
+/// The validators comparing a field against one of its siblings
+pub(crate) const FIELD_COMPARISONS: &[&str] =
+    &["eq_field", "gt_field", "gte_field", "lt_field", "lte_field"];
+
+#[derive(Clone, Copy)]
+pub(crate) struct FieldContext<'a> {
+    fields: &'a syn::Fields,
+    naming: Naming,
+    in_variant: bool,
+    mode: Mode,
+    index: usize,
+}
+
+impl<'a> FieldContext<'a> {
+    pub(crate) fn new(
+        fields: &'a syn::Fields,
+        naming: Naming,
+        in_variant: bool,
+        mode: Mode,
+    ) -> Self {
+        Self {
+            fields,
+            naming,
+            in_variant,
+            mode,
+            index: 0,
+        }
+    }
+
+    /// The context of the field at the given index
+    pub(crate) fn field(self, index: usize) -> Self {
+        Self { index, ..self }
+    }
+
+    pub(crate) fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// The name of the field being validated, as used in error messages
+    pub(crate) fn name(&self) -> String {
+        self.name_of(self.index)
+    }
+
+    /// The name of a field as it is reported in error locations
+    pub(crate) fn name_of(&self, index: usize) -> String {
+        let field = self.fields.iter().nth(index).unwrap();
+
+        let metas: Vec<_> = field
+            .attrs
+            .iter()
+            .filter(|x| x.path.is_ident("validatron"))
+            .filter_map(|attr| attr.parse_meta().ok())
+            .collect();
+
+        // an invalid rename is reported when the field itself is processed
+        let rename = crate::field_rename(&metas).ok().flatten();
+
+        match FieldLocation::new(field, index, self.naming, rename) {
+            FieldLocation::Named(name) => name,
+            FieldLocation::Index(i) => i.to_string(),
+            FieldLocation::Flatten => field.ident.as_ref().unwrap().unraw().to_string(),
+        }
+    }
+
+    /// A reference to the value of a field
+    pub(crate) fn access(&self, index: usize) -> TokenStream {
+        let field = self.fields.iter().nth(index).unwrap();
+
+        match (&field.ident, self.in_variant) {
+            (Some(ident), true) => quote!(#ident),
+            (Some(ident), false) => quote!(&self.#ident),
+            (None, true) => {
+                let ident = format_ident!("_field{}", index);
+                quote!(#ident)
+            }
+            (None, false) => {
+                let index = syn::Index::from(index);
+                quote!(&self.#index)
+            }
+        }
+    }
+
+    /// Resolve the sibling field named by a literal such as `"start_time"` or `"0"`
+    pub(crate) fn sibling(&self, lit: &syn::Lit) -> syn::Result<usize> {
+        let name = match lit {
+            syn::Lit::Str(s) => s.value(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "expected a string literal naming a field, e.g. \"start_time\"",
+                ))
+            }
+        };
+
+        self.find(&name)
+            .ok_or_else(|| syn::Error::new_spanned(lit, format!("unknown field `{}`", name)))
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        let name = name.strip_prefix("r#").unwrap_or(name);
+
+        self.fields
+            .iter()
+            .enumerate()
+            .find(|(i, field)| match &field.ident {
+                Some(ident) => ident.unraw() == name,
+                None => i.to_string() == name,
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Rewrite `self.field` in a user provided expression to the field's binding when
+    /// validating an enum variant, so that expressions work the same as in structs
+    pub(crate) fn rewrite_self(&self, tokens: TokenStream) -> TokenStream {
+        if !self.in_variant {
+            return tokens;
+        }
+
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        let mut out = TokenStream::new();
+        let mut i = 0;
+
+        while i < tokens.len() {
+            if let Some(binding) = self.self_field(&tokens[i..]) {
+                out.extend(quote!((*#binding)));
+                i += 3;
+                continue;
+            }
+
+            match &tokens[i] {
+                TokenTree::Group(group) => {
+                    let mut rewritten =
+                        Group::new(group.delimiter(), self.rewrite_self(group.stream()));
+                    rewritten.set_span(group.span());
+                    out.extend(Some(TokenTree::Group(rewritten)));
+                }
+                tt => out.extend(Some(tt.clone())),
+            }
+
+            i += 1;
+        }
+
+        out
+    }
+
+    // the binding of `self.field` at the start of the tokens, method calls such as
+    // `self.is_valid()` are left to refer to the enum itself
+    fn self_field(&self, tokens: &[TokenTree]) -> Option<TokenStream> {
+        let (field, next) = match tokens {
+            [TokenTree::Ident(this), TokenTree::Punct(dot), field, next @ ..]
+                if this == "self" && dot.as_char() == '.' =>
+            {
+                (field, next.first())
+            }
+            _ => return None,
+        };
+
+        if matches!(next, Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis) {
+            return None;
+        }
+
+        let index = match field {
+            TokenTree::Ident(ident) => self.find(&ident.to_string())?,
+            TokenTree::Literal(lit) => self.find(&lit.to_string())?,
+            _ => return None,
+        };
+
+        Some(self.access(index))
+    }
+}
@@ -1,171 +1,1602 @@
 #![recursion_limit = "512"]
 
 extern crate proc_macro;
+
+mod fields;
+mod naming;
+mod pattern;
+
+use fields::{FieldContext, Mode, FIELD_COMPARISONS};
+use naming::{FieldLocation, Naming};
+use pattern::{pattern_function, pattern_functions, PATTERN_VALIDATORS};
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
+use syn::punctuated::Punctuated;
+
+#[proc_macro_derive(Validate, attributes(validatron))]
+pub fn validatron_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    impl_validatron(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Collects every error found while parsing attributes so that they can all be
+/// reported to the user at once rather than one per compilation.
+#[derive(Default)]
+struct Errors(Option<syn::Error>);
+
+impl Errors {
+    fn push(&mut self, error: syn::Error) {
+        match &mut self.0 {
+            Some(existing) => existing.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    /// record the error of a failed result, returning the value of a successful one
+    fn take<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.push(e);
+                None
+            }
+        }
+    }
+
+    fn finish(self) -> syn::Result<()> {
+        match self.0 {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+fn build_named(name: &str, error: TokenStream, mode: Mode) -> TokenStream {
+    guard_budget(
+        quote! {
+            eb.try_at_named(#name, #error);
+        },
+        mode,
+    )
+}
+
+// a built-in validator, or the check of the same name when only asking whether the value
+// is valid
+fn builtin(name: &str, args: TokenStream, ctx: FieldContext) -> TokenStream {
+    let func = format_ident!("{}", name);
+
+    if ctx.mode().predicate {
+        quote! {
+            ::validatron::checks::#func(#args)
+        }
+    } else {
+        quote! {
+            ::validatron::validators::#func(#args)
+        }
+    }
+}
+
+// synchronous checks are charged to the error budget, see `Validate::validate_within`
+fn charge_budget(validator: TokenStream, mode: Mode) -> TokenStream {
+    if mode.budgeted() {
+        quote! {
+            __validatron_budget.charge(#validator)
+        }
+    } else {
+        validator
+    }
+}
+
+// skips a check once the error budget is exhausted
+fn guard_budget(push: TokenStream, mode: Mode) -> TokenStream {
+    if mode.budgeted() {
+        quote! {
+            if !__validatron_budget.stop() {
+                #push
+            }
+        }
+    } else {
+        push
+    }
+}
+
+// stops iterating over a collection once the error budget is exhausted
+fn break_on_budget(mode: Mode) -> TokenStream {
+    if mode.budgeted() {
+        quote! {
+            if __validatron_budget.stop() {
+                break;
+            }
+        }
+    } else {
+        quote!()
+    }
+}
+
+fn validator_name(path: &syn::Path) -> syn::Result<String> {
+    path.get_ident()
+        .map(|ident| ident.to_string())
+        .ok_or_else(|| syn::Error::new_spanned(path, "expected a validator name"))
+}
+
+fn unknown_validator(path: &syn::Path, name: &str) -> syn::Error {
+    syn::Error::new_spanned(path, format!("unknown validator `{}`", name))
+}
+
+fn missing_context(path: &syn::Path) -> syn::Error {
+    syn::Error::new_spanned(
+        path,
+        "`context_function` requires the type to declare `#[validatron(context = \"...\")]`",
+    )
+}
+
+fn lit_to_path(lit: &syn::Lit) -> syn::Result<syn::Path> {
+    match lit {
+        syn::Lit::Str(s) => s.parse(),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "expected a string literal containing a path, e.g. \"my_function\"",
+        )),
+    }
+}
+
+fn lit_to_expr(lit: &syn::LitStr) -> syn::Result<syn::Expr> {
+    lit.parse().map_err(|e| {
+        syn::Error::new_spanned(lit, format!("invalid expression `{}`: {}", lit.value(), e))
+    })
+}
+
+fn validator_param(lit: &syn::Lit, ctx: FieldContext) -> syn::Result<TokenStream> {
+    // If a user provides a string literal we shall treat it as an expression
+    // this makes our comparison operators much more flexible.
+    if let syn::Lit::Str(lit) = lit {
+        Ok(ctx.rewrite_self(lit_to_expr(lit)?.to_token_stream()))
+    } else {
+        Ok(lit.to_token_stream())
+    }
+}
+
+// the name a type level check is reported under, and the check itself
+fn gen_type_check(
+    meta: &syn::NestedMeta,
+    message: Option<&TokenStream>,
+    mode: Mode,
+) -> syn::Result<(String, TokenStream)> {
+    let mvn = match meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(mvn)) => mvn,
+        syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+            return Err(unknown_validator(path, &validator_name(path)?))
+        }
+        syn::NestedMeta::Meta(syn::Meta::List(list)) => {
+            return Err(syn::Error::new_spanned(
+                list,
+                "nested validators are not supported on types",
+            ))
+        }
+        syn::NestedMeta::Lit(lit) => {
+            return Err(syn::Error::new_spanned(lit, "expected a validator"))
+        }
+    };
+
+    let name = validator_name(&mvn.path)?;
+
+    let lit = &mvn.lit;
+
+    let func = match name.as_str() {
+        "function" | "context_function" | "async_function" => {
+            let custom_func = lit_to_path(lit)?;
+
+            let seg = custom_func
+                .segments
+                .last()
+                .map(|x| x.ident.to_string())
+                .unwrap_or_else(|| quote!(#lit).to_string());
+
+            let check = if name == "function" {
+                quote! {
+                    #custom_func(&self)
+                }
+            } else if name == "async_function" {
+                quote! {
+                    #custom_func(self).await
+                }
+            } else if mode.context {
+                quote! {
+                    #custom_func(&self, ctx)
+                }
+            } else {
+                return Err(missing_context(&mvn.path));
+            };
+
+            let check = match message {
+                Some(message) if !mode.predicate => with_message(check, message),
+                _ => check,
+            };
+
+            (seg, check)
+        }
+        _ => return Err(unknown_validator(&mvn.path, &name)),
+    };
+
+    Ok(func)
+}
+
+fn get_field_validator(
+    meta: &syn::Meta,
+    target: &TokenStream,
+    unit: Option<&TokenStream>,
+    ctx: FieldContext,
+) -> syn::Result<TokenStream> {
+    // such as #[validatron(one_of("nominal", "safe"))] or #[validatron(one_of = "MODES")]
+    if let Some(name) = VALUE_LISTS.iter().find(|x| meta.path().is_ident(x)) {
+        let values = value_list(meta)?;
+        return Ok(builtin(name, quote!(#target, &(#values)[..]), ctx));
+    }
+
+    let validator = match meta {
+        syn::Meta::Path(path) => {
+            let name = validator_name(path)?;
+
+            match name.as_str() {
+                "required" => builtin("is_required", quote!(#target), ctx),
+                "finite" | "not_nan" => builtin(&name, quote!(#target), ctx),
+                "unique" | "sorted" | "strictly_sorted" => builtin(&name, quote!(#target), ctx),
+                "non_empty" => builtin("non_empty", measure(target), ctx),
+                "url" | "option_url" => builtin(&name, quote!(#target, &[]), ctx),
+                name if FORMAT_VALIDATORS.contains(&name) => builtin(name, quote!(#target), ctx),
+                _ => return Err(unknown_validator(path, &name)),
+            }
+        }
+        syn::Meta::List(list) => {
+            let name = validator_name(&list.path)?;
+
+            match name.as_str() {
+                "each" => {
+                    let index = quote!(__validatron_index);
+                    let item = quote!(__validatron_item);
+                    let validators = get_nested_validators(list, &item, ctx)?;
+                    let stop = break_on_budget(ctx.mode());
+
+                    if ctx.mode().predicate {
+                        return Ok(quote! {
+                            (#target).iter().all(|#item| #(#validators)&&*)
+                        });
+                    }
+
+                    // every item is checked concurrently, as the fields are
+                    if ctx.mode().asynchronous {
+                        return Ok(quote! {
+                            {
+                                let results = ::validatron::asynchronous::join_all(
+                                    (#target).iter().map(|#item| async move { [#(#validators),*] }),
+                                )
+                                .await;
+
+                                let mut eb = ::validatron::Error::build();
+
+                                for (#index, results) in results.into_iter().enumerate() {
+                                    for result in results {
+                                        eb.try_at_index(#index, result);
+                                    }
+                                }
+
+                                eb.build()
+                            }
+                        });
+                    }
+
+                    quote! {
+                        {
+                            let mut eb = ::validatron::Error::build();
+
+                            for (#index, #item) in (#target).iter().enumerate() {
+                                #stop
+                                #(eb.try_at_index(#index, #validators);)*
+                            }
+
+                            eb.build()
+                        }
+                    }
+                }
+                "range" | "option_range" => {
+                    let (start, end) = parse_range(list, ctx)?;
+                    builtin(&name, quote!(#target, (#start, #end)), ctx)
+                }
+                // such as #[validatron(url("https", "wss"))]
+                "url" | "option_url" => {
+                    let schemes = url_schemes(list)?;
+                    builtin(&name, quote!(#target, &[#(#schemes),*]), ctx)
+                }
+                "keys" => build_map_entry_validators(list, target, "key", ctx)?,
+                "values" => build_map_entry_validators(list, target, "value", ctx)?,
+                "len" => {
+                    let len = parse_len(list, ctx)?;
+
+                    if len.unit.is_some() && unit.is_some() {
+                        return Err(syn::Error::new_spanned(list, "duplicate `unit`"));
+                    }
+
+                    length_validator(
+                        len.name,
+                        &len.param,
+                        target,
+                        len.unit.as_ref().or(unit),
+                        ctx,
+                    )
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        list,
+                        format!("`{}` does not accept nested validators", name),
+                    ))
+                }
+            }
+        }
+        syn::Meta::NameValue(mnv) => {
+            let name = validator_name(&mnv.path)?;
+
+            // such as #[validatron(gte_field = "start_time")]
+            if FIELD_COMPARISONS.contains(&name.as_str()) {
+                let other = ctx.sibling(&mnv.lit)?;
+
+                let func = format_ident!("{}", name);
+                let field = ctx.name();
+                let other_field = ctx.name_of(other);
+                let other = ctx.access(other);
+
+                if ctx.mode().predicate {
+                    return Ok(quote! {
+                        ::validatron::checks::#func(#target, #other)
+                    });
+                }
+
+                return Ok(quote! {
+                    ::validatron::validators::#func(#target, #field, #other, #other_field)
+                });
+            }
+
+            // such as #[validatron(pattern = "^[A-Z]{3}-\\d+$")]
+            if PATTERN_VALIDATORS.contains(&name.as_str()) {
+                let pattern = pattern_function(&mnv.lit)?;
+
+                let func = match name.as_str() {
+                    "pattern" => "matches_pattern",
+                    _ => "option_matches_pattern",
+                };
+
+                let check = builtin(func, quote!(#target, __validatron_pattern), ctx);
+                let lit = &mnv.lit;
+
+                // a pattern which parses may still be too large to compile
+                let failed = if ctx.mode().predicate {
+                    quote!(false)
+                } else {
+                    quote! {
+                        ::std::result::Result::Err(
+                            ::validatron::Reason::new("invalid_pattern", __validatron_error.to_string())
+                                .with_param("pattern", #lit)
+                                .into(),
+                        )
+                    }
+                };
+
+                return Ok(quote! {
+                    match #pattern() {
+                        ::std::result::Result::Ok(__validatron_pattern) => #check,
+                        ::std::result::Result::Err(__validatron_error) => #failed,
+                    }
+                });
+            }
+
+            let lit = validator_param(&mnv.lit, ctx)?;
+
+            match name.as_str() {
+                "function" if ctx.mode().predicate => {
+                    let custom_func = lit_to_path(&mnv.lit)?;
+                    quote! {
+                        #custom_func(#target).is_ok()
+                    }
+                }
+                "function" => {
+                    let custom_func = lit_to_path(&mnv.lit)?;
+                    quote! {
+                        #custom_func(#target)
+                    }
+                }
+                "context_function" if ctx.mode().context => {
+                    let custom_func = lit_to_path(&mnv.lit)?;
+                    quote! {
+                        #custom_func(#target, ctx)
+                    }
+                }
+                "context_function" => return Err(missing_context(&mnv.path)),
+                "async_function" => {
+                    let custom_func = lit_to_path(&mnv.lit)?;
+                    quote! {
+                        #custom_func(#target).await
+                    }
+                }
+                "predicate" if ctx.mode().predicate => {
+                    let custom_func = lit_to_path(&mnv.lit)?;
+                    quote! {
+                        #custom_func(#target)
+                    }
+                }
+                "predicate" => {
+                    let lit = &mnv.lit;
+
+                    let custom_func = lit_to_path(lit)?;
+
+                    let seg = custom_func
+                        .segments
+                        .last()
+                        .map(|x| x.ident.to_string().to_token_stream())
+                        .unwrap_or_else(|| lit.to_token_stream());
+
+                    let err_msg = format!("Predicate {} failed", seg);
+                    let name = custom_func
+                        .segments
+                        .last()
+                        .map(|x| x.ident.to_string())
+                        .unwrap_or_default();
+
+                    quote! {
+                        if #custom_func(#target) {
+                            Ok(())
+                        } else {
+                            Err(::validatron::Reason::new("predicate", #err_msg)
+                                .with_param("predicate", #name)
+                                .into())
+                        }
+                    }
+                }
+                "required_if" if ctx.mode().predicate => {
+                    let required = builtin("is_required", quote!(#target), ctx);
+                    quote! {
+                        (!(#lit) || #required)
+                    }
+                }
+                "required_if" => {
+                    let required = builtin("is_required", quote!(#target), ctx);
+                    quote! {
+                        if #lit {
+                            #required
+                        } else {
+                            Ok(())
+                        }
+                    }
+                }
+                "required_unless" if ctx.mode().predicate => {
+                    let required = builtin("is_required", quote!(#target), ctx);
+                    quote! {
+                        (#lit || #required)
+                    }
+                }
+                "required_unless" => {
+                    let required = builtin("is_required", quote!(#target), ctx);
+                    quote! {
+                        if #lit {
+                            Ok(())
+                        } else {
+                            #required
+                        }
+                    }
+                }
+                "min" => builtin("min", quote!(#target, #lit), ctx),
+                "option_min" => builtin("option_min", quote!(#target, #lit), ctx),
+                "max" => builtin("max", quote!(#target, #lit), ctx),
+                "option_max" => builtin("option_max", quote!(#target, #lit), ctx),
+                "exclusive_min"
+                | "option_exclusive_min"
+                | "exclusive_max"
+                | "option_exclusive_max" => builtin(&name, quote!(#target, #lit), ctx),
+                "multiple_of" | "max_decimal_places" => builtin(&name, quote!(#target, #lit), ctx),
+                "equal" => builtin("is_equal", quote!(#target, #lit), ctx),
+                "contains" | "not_contains" => builtin(&name, quote!(#target, #lit), ctx),
+                // such as #[validatron(sorted_by_key = "Pass::start_time")]
+                "sorted_by_key" | "strictly_sorted_by_key" => {
+                    let key = lit_to_path(&mnv.lit)?;
+                    builtin(&name, quote!(#target, #key), ctx)
+                }
+                "min_len" | "max_len" | "exact_len" | "len_range" => {
+                    length_validator(&name, &lit, target, unit, ctx)
+                }
+                _ => return Err(unknown_validator(&mnv.path, &name)),
+            }
+        }
+    };
+
+    Ok(validator)
+}
+
+// such as #[validatron(range(min = 0, max_exclusive = 360))], returning the bounds of
+// the range in the order they are checked
+fn parse_range(list: &syn::MetaList, ctx: FieldContext) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut start = None;
+    let mut end = None;
+
+    for item in list.nested.iter() {
+        let mnv = match item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(mnv)) => mnv,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    item,
+                    "expected `min`, `max`, `min_exclusive` or `max_exclusive`, e.g. \
+                     `range(min = 0, max_exclusive = 360)`",
+                ))
+            }
+        };
+
+        let (bound, variant) = match mnv.path.get_ident().map(|x| x.to_string()).as_deref() {
+            Some("min") => (&mut start, quote!(Included)),
+            Some("min_exclusive") => (&mut start, quote!(Excluded)),
+            Some("max") => (&mut end, quote!(Included)),
+            Some("max_exclusive") => (&mut end, quote!(Excluded)),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &mnv.path,
+                    "expected `min`, `max`, `min_exclusive` or `max_exclusive`",
+                ))
+            }
+        };
+
+        if bound.is_some() {
+            return Err(syn::Error::new_spanned(
+                mnv,
+                "a range has one lower and one upper bound",
+            ));
+        }
+
+        let param = validator_param(&mnv.lit, ctx)?;
+        *bound = Some(quote!(::std::ops::Bound::#variant(#param)));
+    }
+
+    if start.is_none() && end.is_none() {
+        return Err(syn::Error::new_spanned(
+            list,
+            "expected at least one bound, e.g. `range(min = 0, max_exclusive = 360)`",
+        ));
+    }
+
+    let unbounded = || quote!(::std::ops::Bound::Unbounded);
+
+    Ok((
+        start.unwrap_or_else(unbounded),
+        end.unwrap_or_else(unbounded),
+    ))
+}
+
+/// Validators of text formats, which have an `option_` variant for optional text
+const FORMAT_VALIDATORS: &[&str] = &[
+    "email",
+    "option_email",
+    "hostname",
+    "option_hostname",
+    "ip",
+    "option_ip",
+    "ipv4",
+    "option_ipv4",
+    "ipv6",
+    "option_ipv6",
+    "cidr",
+    "option_cidr",
+];
+
+fn url_schemes(list: &syn::MetaList) -> syn::Result<Vec<&syn::LitStr>> {
+    if list.nested.is_empty() {
+        return Err(syn::Error::new_spanned(
+            list,
+            "expected at least one scheme, e.g. `url(\"https\")`",
+        ));
+    }
+
+    list.nested
+        .iter()
+        .map(|item| match item {
+            syn::NestedMeta::Lit(syn::Lit::Str(scheme)) => Ok(scheme),
+            item => Err(syn::Error::new_spanned(
+                item,
+                "expected a string literal naming a scheme, e.g. \"https\"",
+            )),
+        })
+        .collect()
+}
+
+const VALUE_LISTS: &[&str] = &[
+    "one_of",
+    "none_of",
+    "one_of_ignore_case",
+    "none_of_ignore_case",
+];
+
+// the values listed by a value list validator, either as literals or as the path to a
+// constant slice or array
+fn value_list(meta: &syn::Meta) -> syn::Result<TokenStream> {
+    let list = match meta {
+        syn::Meta::NameValue(mnv) => {
+            let values = lit_to_path(&mnv.lit)?;
+            return Ok(quote!(#values));
+        }
+        syn::Meta::List(list) if !list.nested.is_empty() => list,
+        meta => {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "expected a list of values, e.g. `one_of(\"a\", \"b\")`, or a constant",
+            ))
+        }
+    };
+
+    let text_only = list
+        .path
+        .get_ident()
+        .is_some_and(|x| x.to_string().ends_with("_ignore_case"));
+
+    let values = list
+        .nested
+        .iter()
+        .map(|item| match item {
+            syn::NestedMeta::Lit(lit @ syn::Lit::Str(_)) => Ok(lit),
+            syn::NestedMeta::Lit(lit) if !text_only => Ok(lit),
+            syn::NestedMeta::Lit(lit) => Err(syn::Error::new_spanned(
+                lit,
+                "expected a string literal, values are compared ignoring case",
+            )),
+            item => Err(syn::Error::new_spanned(
+                item,
+                "expected a literal value, e.g. \"nominal\" or 42",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote!([#(#values),*]))
+}
+
+const LENGTH_VALIDATORS: &[&str] = &["min_len", "max_len", "exact_len", "len_range"];
+
+// sequences count their items, text is measured in the given unit
+fn length_validator(
+    name: &str,
+    param: &TokenStream,
+    target: &TokenStream,
+    unit: Option<&TokenStream>,
+    ctx: FieldContext,
+) -> TokenStream {
+    match unit {
+        Some(unit) => {
+            let func = match name {
+                "min_len" => "is_min_text_length",
+                "max_len" => "is_max_text_length",
+                "exact_len" => "is_exact_text_length",
+                _ => "is_text_length_in_range",
+            };
+
+            builtin(func, quote!(#target, #param, #unit), ctx)
+        }
+        None => {
+            let func = match name {
+                "min_len" => "is_min_length",
+                "max_len" => "is_max_length",
+                "exact_len" => "is_exact_length",
+                _ => "is_length_in_range",
+            };
+
+            let measured = measure(target);
+            builtin(func, quote!(#measured, #param), ctx)
+        }
+    }
+}
+
+// collections implementing `HasLength` are measured without iterating over them, any other
+// iterable by counting its items
+fn measure(target: &TokenStream) -> TokenStream {
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use ::validatron::length::{ByCounting as _, ByLength as _};
+            (&::validatron::length::Measure(#target)).measure()
+        }
+    }
+}
+
+// nested fields are awaited where they implement AsyncValidate, and validated synchronously
+// otherwise
+fn validate_awaited(target: &TokenStream) -> TokenStream {
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use ::validatron::asynchronous::{ByAsync as _, BySync as _};
+            ::validatron::asynchronous::Awaited(#target).validate_nested().await
+        }
+    }
+}
+
+struct Len {
+    name: &'static str,
+    param: TokenStream,
+    unit: Option<TokenStream>,
+}
+
+// such as #[validatron(len(min = 1, max = 64, unit = "chars"))], shorthand for the length
+// validator with the same bounds
+fn parse_len(list: &syn::MetaList, ctx: FieldContext) -> syn::Result<Len> {
+    let mut min = None;
+    let mut max = None;
+    let mut exact = None;
+    let mut unit = None;
+
+    for item in list.nested.iter() {
+        let mnv = match item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(mnv)) => mnv,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    item,
+                    "expected `min`, `max`, `exact` or `unit`, e.g. `len(min = 1, max = 64)`",
+                ))
+            }
+        };
+
+        let slot = match mnv.path.get_ident().map(|x| x.to_string()).as_deref() {
+            Some("min") => &mut min,
+            Some("max") => &mut max,
+            Some("exact") => &mut exact,
+            Some("unit") => &mut unit,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &mnv.path,
+                    "expected `min`, `max`, `exact` or `unit`",
+                ))
+            }
+        };
+
+        if slot.is_some() {
+            return Err(syn::Error::new_spanned(
+                mnv,
+                format!("duplicate `{}`", mnv.path.to_token_stream()),
+            ));
+        }
+
+        *slot = Some(mnv);
+    }
+
+    let unit = unit.map(|mnv| parse_unit(&mnv.lit)).transpose()?;
+    let param = |mnv: &syn::MetaNameValue| validator_param(&mnv.lit, ctx);
+
+    let (name, param) = match (min, max, exact) {
+        (None, None, Some(exact)) => ("exact_len", param(exact)?),
+        (Some(min), Some(max), None) => {
+            let (min, max) = (param(min)?, param(max)?);
+            ("len_range", quote!((#min)..=(#max)))
+        }
+        (Some(min), None, None) => ("min_len", param(min)?),
+        (None, Some(max), None) => ("max_len", param(max)?),
+        (_, _, Some(exact)) => {
+            return Err(syn::Error::new_spanned(
+                exact,
+                "`exact` cannot be combined with `min` or `max`",
+            ))
+        }
+        (None, None, None) => {
+            return Err(syn::Error::new_spanned(
+                list,
+                "expected at least one of `min`, `max` or `exact`",
+            ))
+        }
+    };
+
+    Ok(Len { name, param, unit })
+}
+
+fn parse_unit(lit: &syn::Lit) -> syn::Result<TokenStream> {
+    let unit = match lit {
+        syn::Lit::Str(lit) => lit,
+        lit => return Err(syn::Error::new_spanned(lit, "expected a string literal")),
+    };
+
+    let variant = match unit.value().as_str() {
+        "bytes" => quote!(Bytes),
+        "chars" => quote!(Chars),
+        #[cfg(feature = "use-unicode-segmentation")]
+        "graphemes" => quote!(Graphemes),
+        #[cfg(not(feature = "use-unicode-segmentation"))]
+        "graphemes" => return Err(syn::Error::new_spanned(
+            unit,
+            "the `graphemes` unit requires the `use-unicode-segmentation` feature of validatron",
+        )),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                unit,
+                "unknown unit, expected `bytes`, `chars` or `graphemes`",
+            ))
+        }
+    };
+
+    Ok(quote!(::validatron::validators::Unit::#variant))
+}
+
+// such as #[validatron(min_len = 1, max_len = 64, unit = "chars")], the length validators
+// in the attribute measure text in the unit rather than counting the items of a sequence
+fn build_unit(
+    nested: &Punctuated<syn::NestedMeta, syn::Token![,]>,
+) -> syn::Result<Option<TokenStream>> {
+    let mut unit = None;
+
+    for item in nested.iter() {
+        let mnv = match item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(mnv)) if mnv.path.is_ident("unit") => mnv,
+            _ => continue,
+        };
+
+        if unit.is_some() {
+            return Err(syn::Error::new_spanned(mnv, "duplicate `unit`"));
+        }
+
+        unit = Some(mnv);
+    }
+
+    let unit = match unit {
+        Some(unit) => unit,
+        None => return Ok(None),
+    };
+
+    let measured = nested.iter().any(|item| match item {
+        syn::NestedMeta::Meta(meta) => {
+            LENGTH_VALIDATORS.iter().any(|x| meta.path().is_ident(x)) || meta.path().is_ident("len")
+        }
+        syn::NestedMeta::Lit(_) => false,
+    });
+
+    if !measured {
+        return Err(syn::Error::new_spanned(
+            unit,
+            "`unit` requires a length validator to apply to, e.g. `min_len`",
+        ));
+    }
+
+    parse_unit(&unit.lit).map(Some)
+}
+
+// such as #[validatron(keys(max_len = 32), values(min = 0))]
+//
+// errors are reported under the key of the failing entry, split into "key" and "value"
+// locations so that they can be told apart
+fn build_map_entry_validators(
+    list: &syn::MetaList,
+    target: &TokenStream,
+    part: &str,
+    ctx: FieldContext,
+) -> syn::Result<TokenStream> {
+    let key = quote!(__validatron_key);
+    let value = quote!(__validatron_value);
+
+    let item = if part == "key" { &key } else { &value };
+    let validators = get_nested_validators(list, item, ctx)?;
+    let stop = break_on_budget(ctx.mode());
+
+    if ctx.mode().predicate {
+        return Ok(quote! {
+            (#target).iter().all(|(#key, #value)| #(#validators)&&*)
+        });
+    }
+
+    // every entry is checked concurrently, as the fields are
+    if ctx.mode().asynchronous {
+        return Ok(quote! {
+            {
+                let entries = ::validatron::asynchronous::join_all(
+                    (#target).iter().map(|(#key, #value)| async move {
+                        let mut entry = ::validatron::Error::build();
+
+                        #(entry.try_at_named(#part, #validators);)*
+
+                        (#key, entry)
+                    }),
+                )
+                .await;
+
+                let mut eb = ::validatron::Error::build();
+
+                for (#key, mut entry) in entries {
+                    if entry.contains_errors() {
+                        eb.try_at_named(#key.to_string(), entry.build());
+                    }
+                }
+
+                eb.build()
+            }
+        });
+    }
+
+    Ok(quote! {
+        {
+            let mut eb = ::validatron::Error::build();
+
+            for (#key, #value) in (#target).iter() {
+                #stop
+
+                let mut entry = ::validatron::Error::build();
+
+                #(entry.try_at_named(#part, #validators);)*
+
+                if entry.contains_errors() {
+                    eb.try_at_named(#key.to_string(), entry.build());
+                }
+            }
+
+            eb.build()
+        }
+    })
+}
+
+// such as the min in #[validatron(each(min = 14))]
+fn get_nested_validators(
+    list: &syn::MetaList,
+    target: &TokenStream,
+    ctx: FieldContext,
+) -> syn::Result<Vec<TokenStream>> {
+    if list.nested.is_empty() {
+        return Err(syn::Error::new_spanned(
+            list,
+            "expected at least one nested validator",
+        ));
+    }
+
+    get_field_validators(&list.nested, target, ctx)
+}
+
+// such as #[validatron(min = 42, max = 128, message = "must be between {min} and {max}")]
+fn get_field_validators(
+    nested: &Punctuated<syn::NestedMeta, syn::Token![,]>,
+    target: &TokenStream,
+    ctx: FieldContext,
+) -> syn::Result<Vec<TokenStream>> {
+    let mut errors = Errors::default();
+    let mut validators = vec![];
+
+    let message = errors.take(build_message(nested, target, ctx)).flatten();
+    let guard = errors.take(build_guard(nested, ctx)).flatten();
+    let unit = errors.take(build_unit(nested)).flatten();
+    let allow_nan = errors.take(build_allow_nan(nested)).unwrap_or(false);
+
+    for item in nested.iter() {
+        match item {
+            syn::NestedMeta::Meta(meta) if is_modifier(meta) => {}
+            syn::NestedMeta::Meta(meta) => {
+                if let Some(validator) =
+                    errors.take(get_field_validator(meta, target, unit.as_ref(), ctx))
+                {
+                    let validator = if allow_nan && is_comparison(meta) {
+                        skip_nan(validator, target, ctx)
+                    } else {
+                        validator
+                    };
+
+                    // nested validators carry their own messages, a message applied to the
+                    // whole collection would hide which items failed
+                    let validator = match &message {
+                        Some(message) if !is_nested(meta) && !ctx.mode().predicate => {
+                            with_message(validator, message)
+                        }
+                        _ => validator,
+                    };
+
+                    let validator = match &guard {
+                        Some(condition) if ctx.mode().predicate => quote! {
+                            (!(#condition) || #validator)
+                        },
+                        Some(condition) => quote! {
+                            if #condition {
+                                #validator
+                            } else {
+                                Ok(())
+                            }
+                        },
+                        None => validator,
+                    };
+
+                    // nested validators charge each of their items instead
+                    let validator = if is_nested(meta) {
+                        validator
+                    } else {
+                        charge_budget(validator, ctx.mode())
+                    };
+
+                    validators.push(validator);
+                }
+            }
+            syn::NestedMeta::Lit(lit) => {
+                errors.push(syn::Error::new_spanned(lit, "expected a validator"))
+            }
+        }
+    }
+
+    errors.finish()?;
+
+    Ok(validators)
+}
+
+/// Items that change how the other validators in an attribute are applied
+fn is_modifier(meta: &syn::Meta) -> bool {
+    ["message", "when", "unit", "allow_nan"]
+        .iter()
+        .any(|x| meta.path().is_ident(x))
+}
+
+/// Validators applying other validators to the items of a collection
+fn is_nested(meta: &syn::Meta) -> bool {
+    matches!(meta, syn::Meta::List(_))
+        && ["each", "keys", "values"]
+            .iter()
+            .any(|x| meta.path().is_ident(x))
+}
+
+fn count_validators(nested: &Punctuated<syn::NestedMeta, syn::Token![,]>) -> usize {
+    nested
+        .iter()
+        .filter(|item| !matches!(item, syn::NestedMeta::Meta(meta) if is_modifier(meta)))
+        .count()
+}
 
-#[proc_macro_derive(Validate, attributes(validatron))]
-pub fn validatron_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = syn::parse(input).unwrap();
-    impl_validatron(&ast).into()
+/// Comparisons which reject NaN unless declared with `allow_nan`
+const COMPARISONS: &[&str] = &[
+    "min",
+    "option_min",
+    "max",
+    "option_max",
+    "exclusive_min",
+    "option_exclusive_min",
+    "exclusive_max",
+    "option_exclusive_max",
+    "range",
+    "option_range",
+];
+
+fn is_comparison(meta: &syn::Meta) -> bool {
+    COMPARISONS.iter().any(|x| meta.path().is_ident(x))
 }
 
-fn build_named(name: &str, error: TokenStream) -> TokenStream {
-    quote! {
-        eb.try_at_named(#name, #error);
+// such as #[validatron(min = 0.0, allow_nan)], the comparisons in the attribute pass values
+// which are NaN
+fn build_allow_nan(nested: &Punctuated<syn::NestedMeta, syn::Token![,]>) -> syn::Result<bool> {
+    let mut allow_nan = None;
+
+    for item in nested.iter() {
+        let meta = match item {
+            syn::NestedMeta::Meta(meta) if meta.path().is_ident("allow_nan") => meta,
+            _ => continue,
+        };
+
+        if !matches!(meta, syn::Meta::Path(_)) {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "expected `allow_nan` without a value",
+            ));
+        }
+
+        if allow_nan.is_some() {
+            return Err(syn::Error::new_spanned(meta, "duplicate `allow_nan`"));
+        }
+
+        allow_nan = Some(meta);
     }
-}
 
-fn lit_to_path(lit: &syn::Lit) -> syn::Path {
-    match lit {
-        syn::Lit::Str(s) => syn::parse_str(&s.value()).unwrap(),
-        _ => panic!("invalid literal"),
+    let allow_nan = match allow_nan {
+        Some(allow_nan) => allow_nan,
+        None => return Ok(false),
+    };
+
+    let compared = nested.iter().any(|item| match item {
+        syn::NestedMeta::Meta(meta) => is_comparison(meta),
+        syn::NestedMeta::Lit(_) => false,
+    });
+
+    if !compared {
+        return Err(syn::Error::new_spanned(
+            allow_nan,
+            "`allow_nan` requires a comparison to apply to, e.g. `min`",
+        ));
     }
+
+    Ok(true)
 }
 
-fn gen_type_check(mvn: &syn::MetaNameValue) -> TokenStream {
-    let name = mvn.path.get_ident().unwrap().to_string();
+fn skip_nan(validator: TokenStream, target: &TokenStream, ctx: FieldContext) -> TokenStream {
+    let not_nan = quote!(::validatron::checks::not_nan(#target));
 
-    let lit = &mvn.lit;
+    if ctx.mode().predicate {
+        quote! {
+            (!#not_nan || #validator)
+        }
+    } else {
+        quote! {
+            if #not_nan {
+                #validator
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
 
-    let func = match name.as_str() {
-        "function" => {
-            let custom_func = lit_to_path(lit);
+// such as #[validatron(when = "self.source == Source::Manual", required)], the other
+// validators in the attribute only apply while the condition holds
+fn build_guard(
+    nested: &Punctuated<syn::NestedMeta, syn::Token![,]>,
+    ctx: FieldContext,
+) -> syn::Result<Option<TokenStream>> {
+    let mut guard = None;
 
-            let seg = custom_func
-                .segments
-                .last()
-                .map(|x| x.ident.to_string())
-                .unwrap_or_else(|| quote!(#lit).to_string());
+    for item in nested.iter() {
+        let mnv = match item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(mnv)) if mnv.path.is_ident("when") => mnv,
+            _ => continue,
+        };
 
-            build_named(
-                &seg,
-                quote! {
-                    #custom_func(&self)
-                },
-            )
+        if guard.is_some() {
+            return Err(syn::Error::new_spanned(mnv, "duplicate `when`"));
         }
-        _ => panic!("Unknown validator '{}'", name),
+
+        guard = Some(mnv);
+    }
+
+    let guard = match guard {
+        Some(guard) => guard,
+        None => return Ok(None),
     };
 
-    func
+    if count_validators(nested) == 0 {
+        return Err(syn::Error::new_spanned(
+            guard,
+            "`when` requires a validator to apply to",
+        ));
+    }
+
+    match &guard.lit {
+        syn::Lit::Str(lit) => Ok(Some(ctx.rewrite_self(lit_to_expr(lit)?.to_token_stream()))),
+        lit => Err(syn::Error::new_spanned(
+            lit,
+            "expected a string literal containing an expression, e.g. \"self.enabled\"",
+        )),
+    }
 }
 
-fn get_field_validator(meta: &syn::Meta, target: &TokenStream) -> TokenStream {
-    match meta {
-        syn::Meta::Path(path) => {
-            let name = path.get_ident().unwrap().to_string();
+/// Extract the names of the `{placeholders}` used in a message
+fn message_placeholders(message: &syn::LitStr) -> syn::Result<Vec<String>> {
+    let value = message.value();
+    let mut chars = value.chars().peekable();
+    let mut names = vec![];
 
-            match name.as_str() {
-                "required" => quote! {
-                    ::validatron::validators::is_required(#target)
-                },
-                _ => panic!("Unknown validator '{}'", name),
-            }
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            continue;
         }
-        syn::Meta::List(_) => panic!("not currently supported"),
-        syn::Meta::NameValue(mnv) => {
-            let name = mnv.path.get_ident().unwrap().to_string();
 
-            // If a user provides a string literal we shall treat it as an expression
-            // this makes our comparison operators much more flexible.
-            let lit = if let syn::Lit::Str(lit) = &mnv.lit {
-                let x = syn::parse_str::<syn::Expr>(&lit.value()).unwrap();
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            continue;
+        }
 
-                x.to_token_stream()
-            } else {
-                mnv.lit.to_token_stream()
-            };
+        let placeholder: String = chars.by_ref().take_while(|c| *c != '}').collect();
+        let name = placeholder.split(':').next().unwrap_or_default().trim();
 
-            match name.as_str() {
-                "function" => {
-                    let custom_func = lit_to_path(&mnv.lit);
-                    quote! {
-                        #custom_func(#target)
-                    }
-                }
-                "predicate" => {
-                    let lit = &mnv.lit;
+        if syn::parse_str::<syn::Ident>(name).is_err() {
+            return Err(syn::Error::new_spanned(
+                message,
+                "message placeholders must be named, e.g. `{min}`",
+            ));
+        }
 
-                    let custom_func = lit_to_path(lit);
+        if !names.iter().any(|x| x == name) {
+            names.push(name.to_string());
+        }
+    }
 
-                    let seg = custom_func
-                        .segments
-                        .last()
-                        .map(|x| x.ident.to_string().to_token_stream())
-                        .unwrap_or_else(|| lit.to_token_stream());
+    Ok(names)
+}
 
-                    let err_msg = format!("Predicate {} failed", seg);
-                    quote! {
-                        if #custom_func(#target) {
-                            Ok(())
-                        } else {
-                            Err(::validatron::Error::new(#err_msg))
+// builds the expression rendering the message, placeholders may refer to the parameter of
+// any validator in the same attribute, or the `value` being validated
+fn build_message(
+    nested: &Punctuated<syn::NestedMeta, syn::Token![,]>,
+    target: &TokenStream,
+    ctx: FieldContext,
+) -> syn::Result<Option<TokenStream>> {
+    let mut message = None;
+    let mut params = vec![(String::from("value"), target.clone())];
+
+    for item in nested.iter() {
+        let mnv = match item {
+            // the values of a value list are joined as in the default message
+            syn::NestedMeta::Meta(meta) if VALUE_LISTS.iter().any(|x| meta.path().is_ident(x)) => {
+                if let (Some(name), Ok(values)) = (meta.path().get_ident(), value_list(meta)) {
+                    params.push((
+                        name.to_string(),
+                        quote! {
+                            (#values)
+                                .iter()
+                                .map(::std::string::ToString::to_string)
+                                .collect::<::std::vec::Vec<_>>()
+                                .join(", ")
+                        },
+                    ));
+                }
+
+                continue;
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(mnv)) => mnv,
+            // the bounds of a range may be named by their keys, such as `{max_exclusive}`
+            syn::NestedMeta::Meta(syn::Meta::List(list))
+                if list.path.is_ident("range") || list.path.is_ident("option_range") =>
+            {
+                for item in list.nested.iter() {
+                    if let syn::NestedMeta::Meta(syn::Meta::NameValue(bound)) = item {
+                        if let (Some(name), Ok(param)) =
+                            (bound.path.get_ident(), validator_param(&bound.lit, ctx))
+                        {
+                            params.push((name.to_string(), param));
                         }
                     }
                 }
-                "min" => quote! {
-                    ::validatron::validators::min(#target, #lit)
-                },
-                "option_min" => quote! {
-                    ::validatron::validators::option_min(#target, #lit)
-                },
-                "max" => quote! {
-                    ::validatron::validators::max(#target, #lit)
-                },
-                "option_max" => quote! {
-                    ::validatron::validators::option_max(#target, #lit)
-                },
-                "equal" => quote! {
-                    ::validatron::validators::is_equal(#target, #lit)
-                },
-                "min_len" => quote! {
-                    ::validatron::validators::is_min_length(#target, #lit)
-                },
-                "max_len" => quote! {
-                    ::validatron::validators::is_max_length(#target, #lit)
-                },
-                _ => panic!("Unknown validator '{}'", name),
+
+                continue;
+            }
+            syn::NestedMeta::Meta(syn::Meta::List(list)) if list.path.is_ident("len") => {
+                // errors are reported by the validator itself
+                if let Ok(len) = parse_len(list, ctx) {
+                    params.push(length_param(len.name, len.param));
+
+                    if let Some(syn::NestedMeta::Meta(syn::Meta::NameValue(unit))) =
+                        list.nested.iter().find(
+                            |x| matches!(x, syn::NestedMeta::Meta(m) if m.path().is_ident("unit")),
+                        )
+                    {
+                        params.push((String::from("unit"), unit.lit.to_token_stream()));
+                    }
+                }
+
+                continue;
+            }
+            _ => continue,
+        };
+
+        let name = match mnv.path.get_ident() {
+            Some(ident) => ident.to_string(),
+            None => continue,
+        };
+
+        match (name.as_str(), &mnv.lit) {
+            ("message", syn::Lit::Str(lit)) => {
+                if message.is_some() {
+                    return Err(syn::Error::new_spanned(mnv, "duplicate `message`"));
+                }
+
+                message = Some(lit);
+            }
+            ("message", lit) => {
+                return Err(syn::Error::new_spanned(lit, "expected a string literal"))
+            }
+            (
+                "function"
+                | "context_function"
+                | "async_function"
+                | "predicate"
+                | "when"
+                | "required_if"
+                | "required_unless"
+                | "sorted_by_key"
+                | "strictly_sorted_by_key",
+                _,
+            ) => {}
+            (name, lit) if FIELD_COMPARISONS.contains(&name) => {
+                let other = ctx.access(ctx.sibling(lit)?);
+                params.push((name.to_string(), other))
+            }
+            // units and patterns are named as they were written
+            ("unit", lit) => params.push((name, lit.to_token_stream())),
+            (name, lit) if PATTERN_VALIDATORS.contains(&name) => {
+                params.push((name.to_string(), lit.to_token_stream()))
+            }
+            (name, lit) if LENGTH_VALIDATORS.contains(&name) => {
+                params.push(length_param(name, validator_param(lit, ctx)?))
+            }
+            (_, lit) => params.push((name, validator_param(lit, ctx)?)),
+        }
+    }
+
+    let message = match message {
+        Some(message) => message,
+        None => return Ok(None),
+    };
+
+    if count_validators(nested) == 0 {
+        return Err(syn::Error::new_spanned(
+            message,
+            "`message` requires a validator to apply to",
+        ));
+    }
+
+    let mut args = vec![];
+
+    for name in message_placeholders(message)? {
+        match params.iter().find(|(param, _)| *param == name) {
+            Some((_, expr)) => {
+                let ident = syn::Ident::new(&name, message.span());
+                args.push(quote!(#ident = #expr));
             }
+            None => {
+                let available: Vec<_> =
+                    params.iter().map(|(x, _)| format!("`{{{}}}`", x)).collect();
+
+                return Err(syn::Error::new_spanned(
+                    message,
+                    format!(
+                        "unknown placeholder `{{{}}}`, expected one of {}",
+                        name,
+                        available.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(Some(quote! {
+        ::std::format!(#message, #(#args),*)
+    }))
+}
+
+// ranges have no `Display`, their placeholder renders as in the default message
+fn length_param(name: &str, param: TokenStream) -> (String, TokenStream) {
+    if name == "len_range" {
+        (name.to_string(), quote!(::std::format!("{:?}", #param)))
+    } else {
+        (name.to_string(), param)
+    }
+}
+
+fn with_message(validator: TokenStream, message: &TokenStream) -> TokenStream {
+    quote! {
+        match #validator {
+            Ok(()) => Ok(()),
+            Err(e) => Err(e.with_message(#message)),
         }
     }
 }
 
 // such as #[validatron(function="validate_my_struct")]
-fn build_type_validator(ast: &syn::DeriveInput) -> Vec<TokenStream> {
+fn build_type_validator(ast: &syn::DeriveInput, mode: Mode) -> syn::Result<Vec<TokenStream>> {
+    let mut errors = Errors::default();
     let mut type_validators = vec![];
+
     for attr in ast.attrs.iter().filter(|x| x.path.is_ident("validatron")) {
-        let meta = attr.parse_meta().unwrap();
+        let meta = match errors.take(attr.parse_meta()) {
+            Some(meta) => meta,
+            None => continue,
+        };
 
         use syn::{Meta, NestedMeta};
 
-        if let Meta::List(list) = meta {
-            for item in list.nested.iter() {
-                if let NestedMeta::Meta(Meta::NameValue(mnv)) = item {
-                    type_validators.push(gen_type_check(mnv));
+        match meta {
+            Meta::List(list) => {
+                // type level validators have no sibling fields to refer to
+                let ctx = FieldContext::new(&syn::Fields::Unit, Naming::default(), false, mode);
+                let message = errors.take(build_message(&list.nested, &quote!(self), ctx));
+                let message = message.flatten();
+
+                for item in list.nested.iter() {
+                    if let NestedMeta::Meta(meta) = item {
+                        if ["message", "ignore_serde", "context", "asynchronous"]
+                            .iter()
+                            .any(|x| meta.path().is_ident(x))
+                        {
+                            continue;
+                        }
+                    }
+
+                    if let Some((name, check)) =
+                        errors.take(gen_type_check(item, message.as_ref(), mode))
+                    {
+                        type_validators.push((name, check));
+                    }
+                }
+            }
+            Meta::Path(_) => {}
+            Meta::NameValue(mnv) => errors.push(syn::Error::new_spanned(
+                mnv,
+                "expected `#[validatron]` or `#[validatron(...)]`",
+            )),
+        }
+    }
+
+    errors.finish()?;
+
+    if mode.predicate {
+        Ok(type_validators
+            .into_iter()
+            .map(|(_, check)| {
+                quote! {
+                    if (#check).is_err() {
+                        return false;
+                    }
                 }
+            })
+            .collect())
+    } else if mode.asynchronous {
+        Ok(join_field_validators(
+            type_validators
+                .into_iter()
+                .map(|(name, check)| (FieldLocation::Named(name), check))
+                .collect(),
+        ))
+    } else {
+        Ok(type_validators
+            .into_iter()
+            .map(|(name, check)| build_named(&name, charge_budget(check, mode), mode))
+            .collect())
+    }
+}
+
+// such as #[validatron(rename = "outA")]
+fn field_rename(metas: &[syn::Meta]) -> syn::Result<Option<String>> {
+    let mut rename = None;
+
+    for meta in metas {
+        let list = match meta {
+            syn::Meta::List(list) => list,
+            _ => continue,
+        };
+
+        for item in list.nested.iter() {
+            let mnv = match item {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(mnv)) if is_rename(item) => mnv,
+                _ => continue,
+            };
+
+            match &mnv.lit {
+                syn::Lit::Str(lit) if rename.is_none() => rename = Some(lit.value()),
+                syn::Lit::Str(_) => return Err(syn::Error::new_spanned(mnv, "duplicate `rename`")),
+                lit => return Err(syn::Error::new_spanned(lit, "expected a string literal")),
+            }
+        }
+    }
+
+    Ok(rename)
+}
+
+fn is_rename(item: &syn::NestedMeta) -> bool {
+    matches!(item, syn::NestedMeta::Meta(meta) if meta.path().is_ident("rename"))
+}
+
+// such as #[validatron(context = "MyCtx")]
+fn context_type(ast: &syn::DeriveInput) -> syn::Result<Option<syn::Type>> {
+    let mut context = None;
+
+    let items = ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("validatron"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten();
+
+    for item in items {
+        let mnv = match item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(mnv)) if mnv.path.is_ident("context") => mnv,
+            _ => continue,
+        };
+
+        let ty = match &mnv.lit {
+            syn::Lit::Str(lit) => lit.parse()?,
+            lit => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "expected a string literal containing a type, e.g. \"MyCtx\"",
+                ))
             }
+        };
+
+        if context.is_some() {
+            return Err(syn::Error::new_spanned(mnv, "duplicate `context`"));
+        }
+
+        context = Some(ty);
+    }
+
+    Ok(context)
+}
+
+// such as #[validatron(asynchronous)], or an async_function validator of the type, of a field
+// or nested within each(...), keys(...) or values(...)
+fn is_async(ast: &syn::DeriveInput) -> bool {
+    fn is_async_function(meta: &syn::Meta) -> bool {
+        matches!(meta, syn::Meta::NameValue(mnv) if mnv.path.is_ident("async_function"))
+    }
+
+    fn uses_async(meta: &syn::Meta) -> bool {
+        match meta {
+            syn::Meta::List(list) if is_nested(meta) => list
+                .nested
+                .iter()
+                .any(|item| matches!(item, syn::NestedMeta::Meta(meta) if uses_async(meta))),
+            _ => is_async_function(meta),
         }
     }
 
-    type_validators
+    fn validators(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("validatron"))
+            .filter_map(|attr| match attr.parse_meta() {
+                Ok(syn::Meta::List(list)) => Some(list.nested),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                syn::NestedMeta::Meta(meta) => Some(meta),
+                syn::NestedMeta::Lit(_) => None,
+            })
+            .collect()
+    }
+
+    let fields: Vec<_> = match &ast.data {
+        syn::Data::Struct(ds) => ds.fields.iter().collect(),
+        syn::Data::Enum(de) => de.variants.iter().flat_map(|v| &v.fields).collect(),
+        syn::Data::Union(_) => vec![],
+    };
+
+    validators(&ast.attrs).iter().any(|meta| {
+        matches!(meta, syn::Meta::Path(path) if path.is_ident("asynchronous"))
+            || is_async_function(meta)
+    }) || fields
+        .iter()
+        .any(|field| validators(&field.attrs).iter().any(uses_async))
+}
+
+// such as #[validatron(ignore_serde)]
+fn ignores_serde(ast: &syn::DeriveInput) -> bool {
+    ast.attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("validatron"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|item| matches!(item, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("ignore_serde")))
 }
 
 fn build_field_validators(
     fields: &syn::Fields,
     target_prefix: Option<TokenStream>,
     borrow_fields: bool,
-) -> Vec<TokenStream> {
+    naming: Naming,
+    mode: Mode,
+) -> syn::Result<Vec<TokenStream>> {
+    let mut errors = Errors::default();
+    let ctx = FieldContext::new(fields, naming, target_prefix.is_none(), mode);
+
     // we split these out so we that we only recurse after we have completed all other
     // validation tasks for a given struct
     let mut nested_field_validators = vec![];
     let mut custom_field_validators = vec![];
 
     for (i, field) in fields.iter().enumerate() {
-        // check for and iterate over #[validatron] directives
-        for attr in field.attrs.iter().filter(|x| x.path.is_ident("validatron")) {
-            let meta = attr.parse_meta().unwrap();
+        let metas: Vec<_> = field
+            .attrs
+            .iter()
+            .filter(|x| x.path.is_ident("validatron"))
+            .filter_map(|attr| errors.take(attr.parse_meta()))
+            .collect();
 
+        let rename = errors.take(field_rename(&metas)).flatten();
+        let location = FieldLocation::new(field, i, naming, rename);
+        let ctx = ctx.field(i);
+
+        // check for and iterate over #[validatron] directives
+        for meta in metas {
             let target = field
                 .ident
                 .as_ref()
@@ -187,47 +1618,126 @@ fn build_field_validators(
                     }
                 });
 
-            let push = |func: TokenStream| {
-                if let Some(name) = &field.ident {
-                    let name = name.to_string();
-                    quote! {
-                        eb.try_at_named(#name, #func);
-                    }
-                } else {
-                    quote! {
-                        eb.try_at_index(#i, #func);
-                    }
-                }
-            };
+            let push = |func: TokenStream| (location.clone(), func);
 
             match meta {
                 // #[validatron]
                 syn::Meta::Path(_) => {
-                    let f = quote! { #target.validate() };
+                    let f = if mode.predicate {
+                        quote! { #target.is_valid() }
+                    } else if mode.asynchronous {
+                        let target = if borrow_fields {
+                            quote!(&#target)
+                        } else {
+                            target.clone()
+                        };
+                        validate_awaited(&target)
+                    } else if mode.context {
+                        quote! { #target.validate_with(ctx) }
+                    } else {
+                        quote! { #target.validate_within(__validatron_budget) }
+                    };
                     nested_field_validators.push(push(f))
                 }
                 // #[validatron(...)]
                 syn::Meta::List(list) => {
-                    for item in list.nested.iter() {
-                        if let syn::NestedMeta::Meta(meta) = item {
-                            let validator = if borrow_fields {
-                                get_field_validator(meta, &quote!(&#target))
-                            } else {
-                                get_field_validator(meta, &target)
-                            };
+                    let nested = list
+                        .nested
+                        .into_iter()
+                        .filter(|item| !is_rename(item))
+                        .collect();
 
-                            custom_field_validators.push(push(validator))
-                        }
+                    let validators = if borrow_fields {
+                        get_field_validators(&nested, &quote!(&#target), ctx)
+                    } else {
+                        get_field_validators(&nested, &target, ctx)
+                    };
+
+                    if let Some(validators) = errors.take(validators) {
+                        custom_field_validators.extend(validators.into_iter().map(push))
                     }
                 }
-                _ => panic!("argument not supported"),
+                // #[validatron = ...]
+                syn::Meta::NameValue(mnv) => errors.push(syn::Error::new_spanned(
+                    mnv,
+                    "expected `#[validatron]` or `#[validatron(...)]`",
+                )),
             }
         }
     }
 
-    custom_field_validators.extend(nested_field_validators.into_iter());
+    errors.finish()?;
+
+    custom_field_validators.extend(nested_field_validators);
+
+    if mode.predicate {
+        Ok(custom_field_validators
+            .into_iter()
+            .map(|(_, check)| {
+                quote! {
+                    if !(#check) {
+                        return false;
+                    }
+                }
+            })
+            .collect())
+    } else if mode.asynchronous {
+        Ok(join_field_validators(custom_field_validators))
+    } else {
+        Ok(custom_field_validators
+            .into_iter()
+            .map(|(location, func)| guard_budget(push_field_result(&location, func), mode))
+            .collect())
+    }
+}
+
+fn push_field_result(location: &FieldLocation, result: TokenStream) -> TokenStream {
+    match location {
+        FieldLocation::Named(name) => quote! {
+            eb.try_at_named(#name, #result);
+        },
+        FieldLocation::Index(i) => quote! {
+            eb.try_at_index(#i, #result);
+        },
+        FieldLocation::Flatten => quote! {
+            eb.try_merge(#result);
+        },
+    }
+}
+
+// every check is awaited concurrently, with their results pushed in declaration order
+// once all of them have completed
+fn join_field_validators(validators: Vec<(FieldLocation, TokenStream)>) -> Vec<TokenStream> {
+    if validators.is_empty() {
+        return vec![];
+    }
+
+    let results: Vec<_> = (0..validators.len())
+        .map(|i| format_ident!("__validatron_result{}", i))
+        .collect();
+
+    let (joined, pattern) = validators.iter().zip(&results).rev().fold(
+        (quote!(::std::future::ready(())), quote!(())),
+        |(joined, pattern), ((_, func), result)| {
+            (
+                quote!(::validatron::asynchronous::join(async { #func }, #joined)),
+                quote!((#result, #pattern)),
+            )
+        },
+    );
+
+    let pushes = validators
+        .iter()
+        .zip(&results)
+        .map(|((location, _), result)| push_field_result(location, quote!(#result)));
+
+    vec![quote! {
+        {
+            let #pattern = #joined.await;
 
-    custom_field_validators
+            #(#pushes)*
+        }
+    }]
 }
 
 fn destructure_variant_bindings(fields: &syn::Fields) -> TokenStream {
@@ -265,7 +1775,12 @@ fn destructure_variant_bindings(fields: &syn::Fields) -> TokenStream {
     }
 }
 
-fn build_enum_variant_validator(de: &syn::DataEnum) -> TokenStream {
+fn build_enum_variant_validator(
+    de: &syn::DataEnum,
+    naming: Naming,
+    mode: Mode,
+) -> syn::Result<TokenStream> {
+    let mut errors = Errors::default();
     let mut tokens = Vec::new();
 
     for var in &de.variants {
@@ -273,7 +1788,13 @@ fn build_enum_variant_validator(de: &syn::DataEnum) -> TokenStream {
 
         let escaped = destructure_variant_bindings(&var.fields);
 
-        let field_tokens = build_field_validators(&var.fields, None, false);
+        let field_tokens =
+            build_field_validators(&var.fields, None, false, naming.variant(var), mode);
+
+        let field_tokens = match errors.take(field_tokens) {
+            Some(field_tokens) => field_tokens,
+            None => continue,
+        };
 
         tokens.push(quote! {
             Self::#ident #escaped => {
@@ -282,43 +1803,202 @@ fn build_enum_variant_validator(de: &syn::DataEnum) -> TokenStream {
         });
     }
 
-    quote! {
+    errors.finish()?;
+
+    Ok(quote! {
         match self {
             #(#tokens)*
             _ => {}
         };
-    }
+    })
 }
 
-fn impl_validatron(ast: &syn::DeriveInput) -> TokenStream {
-    let type_validators = build_type_validator(ast);
+fn impl_validatron(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let mut errors = Errors::default();
+
+    let context = errors.take(context_type(ast)).flatten();
+    let mode = Mode {
+        context: context.is_some(),
+        asynchronous: is_async(ast),
+        predicate: false,
+    };
+
+    if mode.context && mode.asynchronous {
+        errors.push(syn::Error::new_spanned(
+            &ast.ident,
+            "a `context` cannot be combined with asynchronous validation",
+        ));
+    }
+
+    let type_validators = errors.take(build_type_validator(ast, mode));
+
+    let naming = Naming::container(ast, !ignores_serde(ast));
 
     let validators = match &ast.data {
-        syn::Data::Struct(ds) => build_field_validators(&ds.fields, Some(quote!(self.)), true),
-        syn::Data::Enum(de) => vec![build_enum_variant_validator(de)],
-        syn::Data::Union(_) => panic!("Union types are not supported"),
+        syn::Data::Struct(ds) => errors.take(build_field_validators(
+            &ds.fields,
+            Some(quote!(self.)),
+            true,
+            naming,
+            mode,
+        )),
+        syn::Data::Enum(de) => errors
+            .take(build_enum_variant_validator(de, naming, mode))
+            .map(|x| vec![x]),
+        syn::Data::Union(du) => {
+            errors.push(syn::Error::new_spanned(
+                du.union_token,
+                "union types are not supported",
+            ));
+            None
+        }
+    };
+
+    errors.finish()?;
+
+    let (type_validators, validators) = (type_validators.unwrap(), validators.unwrap());
+
+    // plain types also check whether they are valid without collecting errors, the same
+    // attributes have been accepted above so these cannot fail
+    let predicate = Mode {
+        predicate: true,
+        ..mode
+    };
+    let checks = match &ast.data {
+        _ if mode.context || mode.asynchronous => vec![],
+        syn::Data::Struct(ds) => {
+            build_field_validators(&ds.fields, Some(quote!(self.)), true, naming, predicate)?
+        }
+        syn::Data::Enum(de) => vec![build_enum_variant_validator(de, naming, predicate)?],
+        syn::Data::Union(_) => vec![],
     };
+    let type_checks = build_type_validator(ast, predicate)?;
+
+    let patterns = pattern_functions(ast);
 
     let derive_target = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let expanded = quote! {
-        const _: () = {
-            extern crate validatron;
+    let body = quote! {
+        let mut eb = ::validatron::Error::build();
+
+        #(#validators)*
 
-            impl #impl_generics ::validatron::Validate for #derive_target #ty_generics #where_clause {
-                fn validate(&self) -> ::validatron::Result<()> {
-                    let mut eb = ::validatron::Error::build();
+        #(#type_validators)*
 
-                    #(#validators)*
+        eb.build()
+    };
+
+    let implementation = match context {
+        // asynchronous types only implement AsyncValidate, the checks of every field are
+        // evaluated concurrently
+        None if mode.asynchronous => {
+            // the trait requires Sync, which generic types only are for some parameters
+            let mut generics = ast.generics.clone();
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#derive_target #ty_generics: ::std::marker::Sync));
+            let (_, _, sync_where_clause) = generics.split_for_impl();
+
+            quote! {
+                impl #impl_generics ::validatron::AsyncValidate for #derive_target #ty_generics #sync_where_clause {
+                    fn validate_async(
+                        &self,
+                    ) -> impl ::std::future::Future<Output = ::validatron::Result<()>> + ::std::marker::Send + '_ {
+                        #[allow(unused_imports)]
+                        use ::validatron::AsyncValidate as _;
+
+                        async move {
+                            // the field and type level checks are also awaited concurrently
+                            let (fields, types) = ::validatron::asynchronous::join(
+                                async {
+                                    let mut eb = ::validatron::Error::build();
+                                    #(#validators)*
+                                    eb.build()
+                                },
+                                async {
+                                    let mut eb = ::validatron::Error::build();
+                                    #(#type_validators)*
+                                    eb.build()
+                                },
+                            )
+                            .await;
+
+                            let mut eb = ::validatron::Error::build();
+                            eb.try_merge(fields).try_merge(types);
+                            eb.build()
+                        }
+                    }
+                }
+            }
+        }
+        // with a context the type only implements ValidateWith, the context is passed to
+        // context functions and on to nested fields as `ctx`
+        Some(context) => quote! {
+            impl #impl_generics ::validatron::ValidateWith<#context> for #derive_target #ty_generics #where_clause {
+                fn validate_with(&self, ctx: &#context) -> ::validatron::Result<()> {
+                    #[allow(unused_imports)]
+                    use ::validatron::ValidateWith as _;
+
+                    let _ = ctx;
+
+                    #body
+                }
+            }
+        },
+        // types without a context ignore any they are given, so that they can be nested within
+        // types which require one, and are nested within asynchronous types through Validate
+        None => {
+            let mut generics = ast.generics.clone();
+            generics
+                .params
+                .push(syn::parse_quote!(__ValidatronContext: ?Sized));
+            let (bridge_generics, _, _) = generics.split_for_impl();
+
+            quote! {
+                impl #impl_generics ::validatron::Validate for #derive_target #ty_generics #where_clause {
+                    fn validate(&self) -> ::validatron::Result<()> {
+                        ::validatron::Validate::validate_within(
+                            self,
+                            &mut ::validatron::Budget::unlimited(),
+                        )
+                    }
+
+                    fn validate_within(
+                        &self,
+                        __validatron_budget: &mut ::validatron::Budget,
+                    ) -> ::validatron::Result<()> {
+                        #body
+                    }
+
+                    fn is_valid(&self) -> bool {
+                        #(#checks)*
 
-                    #(#type_validators)*
+                        #(#type_checks)*
 
-                    eb.build()
+                        true
+                    }
+                }
+
+                impl #bridge_generics ::validatron::ValidateWith<__ValidatronContext> for #derive_target #ty_generics #where_clause {
+                    fn validate_with(&self, _: &__ValidatronContext) -> ::validatron::Result<()> {
+                        ::validatron::Validate::validate(self)
+                    }
                 }
             }
+        }
+    };
+
+    let expanded = quote! {
+        const _: () = {
+            extern crate validatron;
+
+            #(#patterns)*
+
+            #implementation
         };
     };
 
-    expanded
+    Ok(expanded)
 }
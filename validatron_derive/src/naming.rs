@@ -0,0 +1,207 @@
+//! Resolution of the locations that field errors are reported at.
+//!
+//! Fields are keyed by their serde name where one is declared so that error locations
+//! match the payloads users actually send.
+
+use syn::ext::IdentExt;
+
+/// The case conversions supported by `#[serde(rename_all = "...")]`
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(rule: &str) -> Option<Self> {
+        let rule = match rule {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+            _ => return None,
+        };
+
+        Some(rule)
+    }
+
+    /// apply the rule to a snake_case field name, mirroring serde
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                pascal[..1].to_ascii_lowercase() + &pascal[1..]
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+/// The items of every `#[serde(...)]` attribute, attributes that cannot be parsed are
+/// left for serde to report
+fn serde_items(attrs: &[syn::Attribute]) -> impl Iterator<Item = syn::Meta> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|item| match item {
+            syn::NestedMeta::Meta(meta) => Some(meta),
+            syn::NestedMeta::Lit(_) => None,
+        })
+}
+
+/// The value of a serde attribute such as `rename = "a"` or `rename(deserialize = "a")`.
+///
+/// Errors are reported against payloads that have been deserialized, so the
+/// deserialize name takes priority.
+fn serde_name(attrs: &[syn::Attribute], key: &str) -> Option<String> {
+    let lit_str = |lit: &syn::Lit| match lit {
+        syn::Lit::Str(s) => Some(s.value()),
+        _ => None,
+    };
+
+    serde_items(attrs)
+        .filter(|meta| meta.path().is_ident(key))
+        .find_map(|meta| match meta {
+            syn::Meta::NameValue(mnv) => lit_str(&mnv.lit),
+            syn::Meta::List(list) => list.nested.iter().find_map(|item| match item {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(mnv))
+                    if mnv.path.is_ident("deserialize") =>
+                {
+                    lit_str(&mnv.lit)
+                }
+                _ => None,
+            }),
+            syn::Meta::Path(_) => None,
+        })
+}
+
+/// Naming options that apply to all fields of a struct or enum variant
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Naming {
+    use_serde: bool,
+    rename_all: Option<RenameRule>,
+}
+
+impl Naming {
+    /// Naming options for the fields of a struct, or the variants of an enum
+    pub(crate) fn container(ast: &syn::DeriveInput, use_serde: bool) -> Self {
+        let key = match ast.data {
+            syn::Data::Enum(_) => "rename_all_fields",
+            _ => "rename_all",
+        };
+
+        Self {
+            use_serde,
+            rename_all: serde_name(&ast.attrs, key).and_then(|x| RenameRule::from_str(&x)),
+        }
+    }
+
+    /// Naming options for the fields of an enum variant
+    pub(crate) fn variant(self, variant: &syn::Variant) -> Self {
+        let rename_all = serde_name(&variant.attrs, "rename_all")
+            .and_then(|x| RenameRule::from_str(&x))
+            .or(self.rename_all);
+
+        Self { rename_all, ..self }
+    }
+}
+
+/// Where the errors of a field are reported
+#[derive(Clone)]
+pub(crate) enum FieldLocation {
+    Named(String),
+    Index(usize),
+    /// merged into the errors of the containing type, as per `#[serde(flatten)]`
+    Flatten,
+}
+
+impl FieldLocation {
+    pub(crate) fn new(
+        field: &syn::Field,
+        index: usize,
+        naming: Naming,
+        rename: Option<String>,
+    ) -> Self {
+        if let Some(rename) = rename {
+            return FieldLocation::Named(rename);
+        }
+
+        let ident = match &field.ident {
+            Some(ident) => ident.unraw().to_string(),
+            None => return FieldLocation::Index(index),
+        };
+
+        if !naming.use_serde {
+            return FieldLocation::Named(ident);
+        }
+
+        if serde_items(&field.attrs).any(|meta| meta.path().is_ident("flatten")) {
+            return FieldLocation::Flatten;
+        }
+
+        match (serde_name(&field.attrs, "rename"), naming.rename_all) {
+            (Some(rename), _) => FieldLocation::Named(rename),
+            (None, Some(rule)) => FieldLocation::Named(rule.apply_to_field(&ident)),
+            (None, None) => FieldLocation::Named(ident),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rename_rules() {
+        let cases = [
+            ("lowercase", "out_a_b"),
+            ("UPPERCASE", "OUT_A_B"),
+            ("PascalCase", "OutAB"),
+            ("camelCase", "outAB"),
+            ("snake_case", "out_a_b"),
+            ("SCREAMING_SNAKE_CASE", "OUT_A_B"),
+            ("kebab-case", "out-a-b"),
+            ("SCREAMING-KEBAB-CASE", "OUT-A-B"),
+        ];
+
+        for (rule, expected) in cases {
+            let rule = RenameRule::from_str(rule).unwrap();
+            assert_eq!(rule.apply_to_field("out_a_b"), expected);
+        }
+
+        assert!(RenameRule::from_str("Train-Case").is_none());
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+pub(crate) const PATTERN_VALIDATORS: &[&str] = &["pattern", "option_pattern"];
+
+/// The function returning the compiled regex of a pattern, see [`pattern_functions`]
+pub(crate) fn pattern_function(lit: &syn::Lit) -> syn::Result<syn::Ident> {
+    let lit = match lit {
+        syn::Lit::Str(lit) => lit,
+        lit => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "expected a string literal containing a regular expression",
+            ))
+        }
+    };
+
+    check_pattern(lit)?;
+
+    Ok(function_name(&lit.value()))
+}
+
+#[cfg(feature = "use-regex")]
+fn check_pattern(lit: &syn::LitStr) -> syn::Result<()> {
+    regex_syntax::Parser::new()
+        .parse(&lit.value())
+        .map(|_| ())
+        .map_err(|e| syn::Error::new_spanned(lit, format!("invalid pattern: {}", e)))
+}
+
+#[cfg(not(feature = "use-regex"))]
+fn check_pattern(lit: &syn::LitStr) -> syn::Result<()> {
+    Err(syn::Error::new_spanned(
+        lit,
+        "patterns require the `use-regex` feature of validatron",
+    ))
+}
+
+// named after a hash of the pattern, so every use of a pattern within a type shares one regex
+fn function_name(pattern: &str) -> syn::Ident {
+    let mut hasher = DefaultHasher::new();
+    pattern.hash(&mut hasher);
+
+    format_ident!("__validatron_pattern_{:016x}", hasher.finish())
+}
+
+/// Functions compiling each pattern used by the fields of a type the first time it is
+/// matched, shared by all validation modes. Patterns are only parsed by the derive, so
+/// compiling one can still fail, such as when it exceeds the size limit of the regex crate.
+pub(crate) fn pattern_functions(ast: &syn::DeriveInput) -> Vec<TokenStream> {
+    let fields: Vec<&syn::Field> = match &ast.data {
+        syn::Data::Struct(ds) => ds.fields.iter().collect(),
+        syn::Data::Enum(de) => de.variants.iter().flat_map(|x| x.fields.iter()).collect(),
+        syn::Data::Union(_) => vec![],
+    };
+
+    let mut patterns = vec![];
+
+    for attr in fields.iter().flat_map(|x| x.attrs.iter()) {
+        if !attr.path.is_ident("validatron") {
+            continue;
+        }
+
+        // malformed attributes are reported while building the validators
+        if let Ok(meta) = attr.parse_meta() {
+            collect_patterns(&meta, &mut patterns);
+        }
+    }
+
+    patterns
+        .iter()
+        .map(|pattern| {
+            let name = function_name(pattern);
+
+            quote! {
+                fn #name() -> ::std::result::Result<
+                    &'static ::validatron::regex::Regex,
+                    &'static ::validatron::regex::Error,
+                > {
+                    static PATTERN: ::std::sync::OnceLock<
+                        ::std::result::Result<::validatron::regex::Regex, ::validatron::regex::Error>,
+                    > = ::std::sync::OnceLock::new();
+
+                    PATTERN
+                        .get_or_init(|| ::validatron::regex::Regex::new(#pattern))
+                        .as_ref()
+                }
+            }
+        })
+        .collect()
+}
+
+fn collect_patterns(meta: &syn::Meta, patterns: &mut Vec<String>) {
+    match meta {
+        syn::Meta::List(list) => {
+            for item in list.nested.iter() {
+                if let syn::NestedMeta::Meta(meta) = item {
+                    collect_patterns(meta, patterns);
+                }
+            }
+        }
+        syn::Meta::NameValue(mnv) => {
+            let is_pattern = PATTERN_VALIDATORS.iter().any(|x| mnv.path.is_ident(x));
+
+            if let (true, syn::Lit::Str(lit)) = (is_pattern, &mnv.lit) {
+                if !patterns.contains(&lit.value()) {
+                    patterns.push(lit.value());
+                }
+            }
+        }
+        syn::Meta::Path(_) => {}
+    }
+}